@@ -63,7 +63,7 @@
 //! - first loop invariant is whether a `MEDIAN_ELEMENT` is what we want
 //! - second loop invariant is whether lower bound is larger than higher bound
 //! - runs in **log2(N)** which means it needs at most 20 repetitions to find an
-//!      element in an array of one million elements.
+//!   element in an array of one million elements.
 
 /// Searches for given element in provided slice. The algorithm assumes that the
 /// array is sorted. It returns element index if it's present or `None` if not.
@@ -73,6 +73,7 @@
 /// type `T`:
 /// 1. We want to be able to tell whether two elements `T` are equal.
 /// 2. We want to be able to tell which of two elements `T` is larger.
+///
 /// Rust has two traits which get the job done. Type `T` has to implement both
 /// [`PartialEq`] and [`PartialOrd`].
 ///
@@ -112,6 +113,298 @@ where
     }
 }
 
+/// Returns `floor(sqrt(n))` without using floating point numbers, which lose
+/// precision for large `n`. The root is found by binary search for the
+/// largest candidate whose square is not larger than `n`.
+pub fn integer_sqrt(n: u64) -> u64 {
+    // The square of a u64 doesn't fit into u64, but it does fit into u128.
+    let n = u128::from(n);
+    let mut lower_bound = 0u128;
+    let mut upper_bound = n;
+
+    // Invariant: the root is within the inclusive bounds.
+    while lower_bound < upper_bound {
+        // Rounds up so that the median is never the lower bound. Otherwise the
+        // loop would never end when the bounds are neighbours.
+        let median = (lower_bound + upper_bound).div_ceil(2);
+
+        if median * median <= n {
+            lower_bound = median;
+        } else {
+            upper_bound = median - 1;
+        }
+    }
+
+    lower_bound as u64
+}
+
+/// Searches for the first occurrence of given element in a sorted slice. If
+/// the element is present multiple times, [`binary_search`] returns any of
+/// them, while this function returns the one with the lowest index.
+///
+/// Unlike in [`binary_search`], the upper bound is exclusive. The search
+/// doesn't stop when it finds the element. It keeps moving the upper bound
+/// down to the found element until the bounds meet.
+///
+/// [`binary_search`]: fn.binary_search.html
+pub fn binary_search_leftmost<T>(element: &T, array: &[T]) -> Option<usize>
+where
+    T: PartialEq + PartialOrd,
+{
+    let mut lower_bound = 0;
+    let mut upper_bound = array.len();
+
+    while lower_bound < upper_bound {
+        let median = (lower_bound + upper_bound) / 2;
+
+        if array[median] < *element {
+            lower_bound = median + 1;
+        } else {
+            upper_bound = median;
+        }
+    }
+
+    // Lower bound now points to the first element which is not smaller than
+    // the one we look for.
+    if lower_bound < array.len() && array[lower_bound] == *element {
+        Some(lower_bound)
+    } else {
+        None
+    }
+}
+
+/// Searches for the last occurrence of given element in a sorted slice. It's
+/// the mirror image of [`binary_search_leftmost`].
+///
+/// [`binary_search_leftmost`]: fn.binary_search_leftmost.html
+pub fn binary_search_rightmost<T>(element: &T, array: &[T]) -> Option<usize>
+where
+    T: PartialEq + PartialOrd,
+{
+    let mut lower_bound = 0;
+    let mut upper_bound = array.len();
+
+    while lower_bound < upper_bound {
+        let median = (lower_bound + upper_bound) / 2;
+
+        if *element < array[median] {
+            upper_bound = median;
+        } else {
+            lower_bound = median + 1;
+        }
+    }
+
+    // Lower bound now points to the first element which is larger than the
+    // one we look for. The last occurrence is right before it.
+    if lower_bound > 0 && array[lower_bound - 1] == *element {
+        Some(lower_bound - 1)
+    } else {
+        None
+    }
+}
+
+/// Returns inclusive indices of the first and the last occurrence of given
+/// element in a sorted slice, or `None` if the element is not present.
+pub fn equal_range<T>(element: &T, array: &[T]) -> Option<(usize, usize)>
+where
+    T: PartialEq + PartialOrd,
+{
+    let first = binary_search_leftmost(element, array)?;
+    let last = binary_search_rightmost(element, array)?;
+
+    Some((first, last))
+}
+
+/// Counts how many times given element occurs in a sorted slice in O(log N).
+pub fn count_occurrences<T>(element: &T, array: &[T]) -> usize
+where
+    T: PartialEq + PartialOrd,
+{
+    equal_range(element, array).map_or(0, |(first, last)| last - first + 1)
+}
+
+/// Returns the first index in `0..len` for which the predicate is true, or
+/// `len` if it's false everywhere. The predicate must be monotonic: false for
+/// some prefix of the range and true for the rest of it.
+///
+/// This generalizes binary search from looking for an element to looking for
+/// the boundary where any monotonic condition flips. It calls the predicate
+/// O(log len) times.
+pub fn partition_point<F>(len: usize, mut pred: F) -> usize
+where
+    F: FnMut(usize) -> bool,
+{
+    // The boundary is always within the bounds. Upper bound is exclusive,
+    // because the predicate might be false everywhere.
+    let mut lower_bound = 0;
+    let mut upper_bound = len;
+
+    while lower_bound < upper_bound {
+        let median = (lower_bound + upper_bound) / 2;
+
+        if pred(median) {
+            upper_bound = median;
+        } else {
+            lower_bound = median + 1;
+        }
+    }
+
+    lower_bound
+}
+
+/// Returns how many elements of a sorted slice are smaller than given element.
+/// That's also the index at which the element would be inserted to keep the
+/// slice sorted, and the index of its first occurrence if it's present.
+pub fn rank<T>(element: &T, array: &[T]) -> usize
+where
+    T: PartialOrd,
+{
+    partition_point(array.len(), |index| array[index] >= *element)
+}
+
+/// Searches for given element in a virtual sorted array of given length,
+/// whose elements are computed on demand by calling `access` with their
+/// index. This way a monotonic function can be searched without storing all
+/// of its values. The function is called O(log len) times.
+pub fn binary_search_fn<T, F>(element: &T, len: usize, access: F) -> Option<usize>
+where
+    T: PartialEq + PartialOrd,
+    F: Fn(usize) -> T,
+{
+    let index = partition_point(len, |index| access(index) >= *element);
+
+    if index < len && access(index) == *element {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+/// Searches for given element in a slice sorted in descending order. It's
+/// [`binary_search`] with the bound-moving logic flipped: if the median is
+/// larger than the element we look for, the element must be to the right of
+/// it. Unlike [`binary_search`], it returns the first occurrence of the
+/// element and handles an empty slice.
+///
+/// [`binary_search`]: fn.binary_search.html
+pub fn binary_search_desc<T>(element: &T, array: &[T]) -> Option<usize>
+where
+    T: PartialEq + PartialOrd,
+{
+    let index = partition_point(array.len(), |index| array[index] <= *element);
+
+    if index < array.len() && array[index] == *element {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+/// Returns the floor and the ceiling of given element in a sorted slice. The
+/// floor is the largest element not larger than the given one, the ceiling is
+/// the smallest element not smaller than it. Either is `None` if the slice has
+/// no such element. If the element is present, both equal it.
+pub fn floor_ceil<T>(element: &T, array: &[T]) -> (Option<T>, Option<T>)
+where
+    T: PartialOrd + Clone,
+{
+    // Index of the first element larger than the given one. The floor is
+    // right before it.
+    let after_floor = partition_point(array.len(), |index| array[index] > *element);
+    let ceil_index = rank(element, array);
+
+    let floor = after_floor.checked_sub(1).map(|index| array[index].clone());
+    let ceil = array.get(ceil_index).cloned();
+
+    (floor, ceil)
+}
+
+/// Returns the index of the element of a sorted slice which is the closest to
+/// given one, or `None` if the slice is empty. If two elements are equally
+/// close, the smaller one is chosen.
+///
+/// The closest element is one of the two neighbours of the point at which the
+/// given element would be inserted, see [`rank`].
+///
+/// [`rank`]: fn.rank.html
+pub fn closest_value(element: i64, array: &[i64]) -> Option<usize> {
+    if array.is_empty() {
+        return None;
+    }
+
+    let insertion_point = rank(&element, array);
+    if insertion_point == 0 {
+        return Some(0);
+    }
+    if insertion_point == array.len() {
+        return Some(array.len() - 1);
+    }
+
+    // The difference of two i64 doesn't fit into i64, but its absolute value
+    // fits into u64.
+    let below = insertion_point - 1;
+    if element.abs_diff(array[below]) <= element.abs_diff(array[insertion_point]) {
+        Some(below)
+    } else {
+        Some(insertion_point)
+    }
+}
+
+/// Finds a root of a continuous function within the interval `lo..=hi` by
+/// bisection. The function must have opposite signs at the two ends, so that
+/// by the intermediate value theorem it crosses zero somewhere in between.
+/// Returns `None` if it doesn't.
+///
+/// Like in [`binary_search`], the interval is halved in each repetition. The
+/// half whose ends still have opposite signs is kept. The search stops when
+/// the interval is narrower than the tolerance, when the median is an exact
+/// root, or after `max_iters` repetitions, and returns the median.
+///
+/// [`binary_search`]: fn.binary_search.html
+pub fn bisect_root<F>(
+    f: F,
+    mut lo: f64,
+    mut hi: f64,
+    tolerance: f64,
+    max_iters: usize,
+) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let mut f_lo = f(lo);
+    let f_hi = f(hi);
+    if f_lo == 0.0 {
+        return Some(lo);
+    }
+    if f_hi == 0.0 {
+        return Some(hi);
+    }
+    if f_lo.signum() == f_hi.signum() || f_lo.is_nan() || f_hi.is_nan() {
+        return None;
+    }
+
+    for _ in 0..max_iters {
+        if (hi - lo).abs() < tolerance {
+            break;
+        }
+
+        let median = lo + (hi - lo) / 2.0;
+        let f_median = f(median);
+        if f_median == 0.0 {
+            return Some(median);
+        }
+
+        if f_median.signum() == f_lo.signum() {
+            lo = median;
+            f_lo = f_median;
+        } else {
+            hi = median;
+        }
+    }
+
+    Some(lo + (hi - lo) / 2.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,4 +445,268 @@ mod tests {
 
         assert_eq!(search_result, Some(2));
     }
+
+    #[test]
+    fn integer_sqrt_of_perfect_squares() {
+        assert_eq!(integer_sqrt(0), 0);
+        assert_eq!(integer_sqrt(1), 1);
+        assert_eq!(integer_sqrt(4), 2);
+        assert_eq!(integer_sqrt(144), 12);
+        assert_eq!(integer_sqrt(1 << 62), 1 << 31);
+    }
+
+    #[test]
+    fn integer_sqrt_floors() {
+        assert_eq!(integer_sqrt(2), 1);
+        assert_eq!(integer_sqrt(3), 1);
+        assert_eq!(integer_sqrt(143), 11);
+        assert_eq!(integer_sqrt(145), 12);
+        assert_eq!(integer_sqrt((1 << 62) - 1), (1 << 31) - 1);
+        assert_eq!(integer_sqrt((1 << 62) + 1), 1 << 31);
+    }
+
+    #[test]
+    fn integer_sqrt_of_max() {
+        assert_eq!(integer_sqrt(u64::MAX), u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn leftmost_and_rightmost_find_ends_of_run() {
+        let haystack = [1, 2, 2, 2, 5];
+
+        assert_eq!(binary_search_leftmost(&2, &haystack), Some(1));
+        assert_eq!(binary_search_rightmost(&2, &haystack), Some(3));
+        assert_eq!(binary_search_leftmost(&3, &haystack), None);
+        assert_eq!(binary_search_rightmost(&3, &haystack), None);
+        assert_eq!(binary_search_leftmost(&1, &[]), None);
+        assert_eq!(binary_search_rightmost(&1, &[]), None);
+    }
+
+    #[test]
+    fn equal_range_of_run() {
+        let haystack = [1, 2, 2, 2, 5];
+
+        assert_eq!(equal_range(&2, &haystack), Some((1, 3)));
+    }
+
+    #[test]
+    fn equal_range_of_single_occurrence() {
+        let haystack = [1, 2, 2, 2, 5];
+
+        assert_eq!(equal_range(&1, &haystack), Some((0, 0)));
+        assert_eq!(equal_range(&5, &haystack), Some((4, 4)));
+    }
+
+    #[test]
+    fn equal_range_of_absent_element() {
+        let haystack = [1, 2, 2, 2, 5];
+
+        assert_eq!(equal_range(&0, &haystack), None);
+        assert_eq!(equal_range(&3, &haystack), None);
+        assert_eq!(equal_range(&6, &haystack), None);
+    }
+
+    #[test]
+    fn count_occurrences_of_absent_element() {
+        let haystack = [1, 2, 2, 2, 5];
+
+        assert_eq!(count_occurrences(&3, &haystack), 0);
+        assert_eq!(count_occurrences(&3, &[]), 0);
+    }
+
+    #[test]
+    fn count_occurrences_of_single_element() {
+        let haystack = [1, 2, 2, 2, 5];
+
+        assert_eq!(count_occurrences(&5, &haystack), 1);
+    }
+
+    #[test]
+    fn count_occurrences_of_runs() {
+        let haystack = [1, 1, 1, 2, 2, 3, 4, 4, 4, 4];
+
+        assert_eq!(count_occurrences(&1, &haystack), 3);
+        assert_eq!(count_occurrences(&2, &haystack), 2);
+        assert_eq!(count_occurrences(&4, &haystack), 4);
+        assert_eq!(count_occurrences(&7, &[7, 7, 7]), 3);
+    }
+
+    #[test]
+    fn partition_point_finds_boundary() {
+        for len in 6..20 {
+            assert_eq!(partition_point(len, |i| i >= 5), 5);
+        }
+    }
+
+    #[test]
+    fn partition_point_when_predicate_is_false_everywhere() {
+        assert_eq!(partition_point(0, |_| false), 0);
+        assert_eq!(partition_point(5, |i| i >= 5), 5);
+        assert_eq!(partition_point(10, |_| false), 10);
+    }
+
+    #[test]
+    fn partition_point_when_predicate_is_true_everywhere() {
+        assert_eq!(partition_point(1, |_| true), 0);
+        assert_eq!(partition_point(10, |_| true), 0);
+    }
+
+    #[test]
+    fn partition_point_calls_predicate_logarithmically() {
+        let mut calls = 0;
+
+        partition_point(1_000_000, |i| {
+            calls += 1;
+            i >= 123_456
+        });
+
+        assert!(calls <= 20);
+    }
+
+    #[test]
+    fn rank_of_present_element_is_leftmost_index() {
+        let haystack = [1, 2, 2, 2, 5, 5, 7];
+
+        for needle in &[1, 2, 5, 7] {
+            assert_eq!(
+                Some(rank(needle, &haystack)),
+                binary_search_leftmost(needle, &haystack)
+            );
+        }
+    }
+
+    #[test]
+    fn rank_of_absent_element_is_insertion_point() {
+        let haystack = [1, 2, 2, 2, 5, 5, 7];
+
+        assert_eq!(rank(&0, &haystack), 0);
+        assert_eq!(rank(&3, &haystack), 4);
+        assert_eq!(rank(&6, &haystack), 6);
+        assert_eq!(rank(&8, &haystack), 7);
+        assert_eq!(rank(&8, &[]), 0);
+    }
+
+    #[test]
+    fn binary_search_fn_finds_squares() {
+        let square = |index: usize| index * index;
+
+        assert_eq!(binary_search_fn(&0, 1000, square), Some(0));
+        assert_eq!(binary_search_fn(&144, 1000, square), Some(12));
+        assert_eq!(binary_search_fn(&998_001, 1000, square), Some(999));
+    }
+
+    #[test]
+    fn binary_search_fn_returns_none_if_element_is_not_present() {
+        let square = |index: usize| index * index;
+
+        assert_eq!(binary_search_fn(&145, 1000, square), None);
+        assert_eq!(binary_search_fn(&1_000_000, 1000, square), None);
+        assert_eq!(binary_search_fn(&0, 0, square), None);
+    }
+
+    #[test]
+    fn binary_search_desc_finds_present_elements() {
+        let haystack = [50, 40, 30, 20, 20, 10, 0];
+
+        assert_eq!(binary_search_desc(&50, &haystack), Some(0));
+        assert_eq!(binary_search_desc(&30, &haystack), Some(2));
+        assert_eq!(binary_search_desc(&20, &haystack), Some(3));
+        assert_eq!(binary_search_desc(&0, &haystack), Some(6));
+    }
+
+    #[test]
+    fn binary_search_desc_returns_none_if_element_is_not_present() {
+        let haystack = [50, 40, 30, 20, 20, 10, 0];
+
+        assert_eq!(binary_search_desc(&60, &haystack), None);
+        assert_eq!(binary_search_desc(&35, &haystack), None);
+        assert_eq!(binary_search_desc(&-5, &haystack), None);
+        assert_eq!(binary_search_desc(&5, &[]), None);
+    }
+
+    #[test]
+    fn floor_ceil_of_element_between_two_elements() {
+        let haystack = [10, 20, 30, 40];
+
+        assert_eq!(floor_ceil(&25, &haystack), (Some(20), Some(30)));
+    }
+
+    #[test]
+    fn floor_ceil_of_element_outside_of_array() {
+        let haystack = [10, 20, 30, 40];
+
+        assert_eq!(floor_ceil(&5, &haystack), (None, Some(10)));
+        assert_eq!(floor_ceil(&45, &haystack), (Some(40), None));
+        assert_eq!(floor_ceil(&5, &[]), (None, None));
+    }
+
+    #[test]
+    fn floor_ceil_of_present_element() {
+        let haystack = [10, 20, 20, 30, 40];
+
+        assert_eq!(floor_ceil(&20, &haystack), (Some(20), Some(20)));
+        assert_eq!(floor_ceil(&10, &haystack), (Some(10), Some(10)));
+        assert_eq!(floor_ceil(&40, &haystack), (Some(40), Some(40)));
+    }
+
+    #[test]
+    fn closest_value_breaks_tie_towards_smaller_element() {
+        let haystack = [10, 20, 30, 40];
+
+        assert_eq!(closest_value(25, &haystack), Some(1));
+        assert_eq!(closest_value(26, &haystack), Some(2));
+        assert_eq!(closest_value(24, &haystack), Some(1));
+    }
+
+    #[test]
+    fn closest_value_of_present_element_is_its_index() {
+        let haystack = [10, 20, 30, 40];
+
+        assert_eq!(closest_value(10, &haystack), Some(0));
+        assert_eq!(closest_value(30, &haystack), Some(2));
+    }
+
+    #[test]
+    fn closest_value_beyond_array_is_its_end() {
+        let haystack = [10, 20, 30, 40];
+
+        assert_eq!(closest_value(i64::MIN, &haystack), Some(0));
+        assert_eq!(closest_value(i64::MAX, &haystack), Some(3));
+        assert_eq!(closest_value(0, &[]), None);
+    }
+
+    #[test]
+    fn bisect_root_finds_square_root_of_two() {
+        let root = bisect_root(|x| x * x - 2.0, 0.0, 2.0, 1e-12, 100).unwrap();
+
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bisect_root_works_in_both_directions() {
+        let root = bisect_root(|x| 1.0 - x, 0.0, 3.0, 1e-9, 100).unwrap();
+        assert!((root - 1.0).abs() < 1e-9);
+
+        let root = bisect_root(|x| x.cos(), 3.0, 0.0, 1e-9, 100).unwrap();
+        assert!((root - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bisect_root_accepts_root_at_bound() {
+        assert_eq!(bisect_root(|x| x - 1.0, 1.0, 5.0, 1e-9, 100), Some(1.0));
+    }
+
+    #[test]
+    fn bisect_root_rejects_same_signs() {
+        assert_eq!(bisect_root(|x| x * x + 1.0, -1.0, 1.0, 1e-9, 100), None);
+        assert_eq!(bisect_root(|x| x * x - 2.0, 2.0, 3.0, 1e-9, 100), None);
+    }
+
+    #[test]
+    fn bisect_root_stops_after_max_iterations() {
+        // Each iteration halves the interval of width 2.
+        let root = bisect_root(|x| x * x - 2.0, 0.0, 2.0, 0.0, 3).unwrap();
+
+        assert!((root - std::f64::consts::SQRT_2).abs() <= 0.125);
+    }
 }
@@ -99,6 +99,8 @@
 //!     \
 //!     Niklaus Wirth 1976, 61
 
+use super::a_001_binary_search::partition_point;
+
 /// Takes a mutable slice of comparable elements and sorts them in ASC order.
 pub fn straight_insertion<T>(array: &mut [T])
 where
@@ -127,10 +129,58 @@ where
     }
 }
 
+/// Sorts only elements in range `lo..hi` of the slice, leaving the rest of the
+/// slice untouched. This lets other sorts delegate small partitions to
+/// straight insertion without re-slicing.
+///
+/// Panics if the range is not within the slice.
+pub fn insertion_sort_range<T>(array: &mut [T], lo: usize, hi: usize)
+where
+    T: PartialEq + PartialOrd,
+{
+    assert!(lo <= hi && hi <= array.len(), "range out of bounds");
+
+    // The same algorithm as above, except the start of the destination
+    // sequence is `lo` instead of zero.
+    for index in (lo + 1)..hi {
+        let mut tracker = index;
+
+        while tracker > lo && array[tracker] < array[tracker - 1] {
+            array.swap(tracker, tracker - 1);
+
+            tracker -= 1;
+        }
+    }
+}
+
+/// Sorts in ASC order like [`straight_insertion`], but finds the place for
+/// each element by binary search in the destination sequence instead of
+/// comparing it with each neighbour on the way. That reduces comparisons to
+/// O(n log n). Then the whole block between the place and the element is
+/// shifted by one position at once. The number of moves stays O(n^2), but a
+/// block rotation is much cheaper than swapping neighbours one by one.
+///
+/// The place of the element is after all elements which are equal to it,
+/// hence the sort is stable.
+///
+/// [`straight_insertion`]: fn.straight_insertion.html
+pub fn insertion_sort_block_move<T>(array: &mut [T])
+where
+    T: PartialEq + PartialOrd,
+{
+    for index in 1..array.len() {
+        // First element of the destination sequence which is larger.
+        let place = partition_point(index, |tracker| array[tracker] > array[index]);
+
+        // Moves the element to its place and shifts the block right of it.
+        array[place..=index].rotate_right(1);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_helpers::is_sorted;
+    use crate::test_helpers::*;
 
     #[test]
     fn it_handles_empty_array() {
@@ -201,27 +251,109 @@ mod tests {
 
     #[test]
     fn it_sorts_example() {
-        let mut array = vec![44, 55, 12, 42, 94, 18, 6, 67];
+        let array = [44, 55, 12, 42, 94, 18, 6, 67];
 
-        straight_insertion(&mut array);
-
-        assert!(is_sorted(&array));
+        assert_sorted_permutation(&array, straight_insertion);
     }
 
     #[test]
     fn fuzzy_test() {
-        extern crate rand;
-        use rand::prelude::SliceRandom;
-
-        let mut rng = rand::thread_rng();
         let mut numbers: Vec<u16> = (1..100).collect();
 
-        for _ in 0..100 {
-            numbers.shuffle(&mut rng);
+        for seed in 0..100 {
+            shuffle(&mut numbers, seed);
 
             straight_insertion(&mut numbers);
 
             assert!(is_sorted(&numbers));
         }
     }
+
+    #[test]
+    fn range_sorts_only_middle_segment() {
+        let mut array = vec![9, 8, 7, 6, 5, 4, 3, 2, 1];
+
+        insertion_sort_range(&mut array, 2, 6);
+
+        assert_eq!(array, vec![9, 8, 4, 5, 6, 7, 3, 2, 1]);
+    }
+
+    #[test]
+    fn range_handles_empty_and_single_element_ranges() {
+        let mut array = vec![3, 2, 1];
+
+        insertion_sort_range(&mut array, 1, 1);
+        insertion_sort_range(&mut array, 3, 3);
+        insertion_sort_range(&mut array, 0, 1);
+
+        assert_eq!(array, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn range_sorts_whole_slice() {
+        let mut array = vec![44, 55, 12, 42, 94, 18, 6, 67];
+
+        let len = array.len();
+        insertion_sort_range(&mut array, 0, len);
+
+        assert!(is_sorted(&array));
+    }
+
+    #[test]
+    #[should_panic]
+    fn range_panics_when_out_of_bounds() {
+        let mut array = vec![3, 2, 1];
+
+        insertion_sort_range(&mut array, 1, 4);
+    }
+
+    #[test]
+    fn it_is_stable_by_key() {
+        let mut pairs = keyed_pairs(200, 7, 1);
+
+        assert!(sort_and_check_stable_by_key(&mut pairs, straight_insertion));
+    }
+
+    #[test]
+    fn block_move_sorts_example() {
+        let array = [44, 55, 12, 42, 94, 18, 6, 67];
+
+        assert_sorted_permutation(&array, insertion_sort_block_move);
+    }
+
+    #[test]
+    fn block_move_handles_short_arrays() {
+        let mut empty: Vec<u8> = Vec::new();
+        let mut single = vec![4];
+
+        insertion_sort_block_move(&mut empty);
+        insertion_sort_block_move(&mut single);
+
+        assert_eq!(single, vec![4]);
+    }
+
+    #[test]
+    fn block_move_is_stable() {
+        let mut pairs = keyed_pairs(200, 7, 1);
+
+        assert!(sort_and_check_stable_by_key(
+            &mut pairs,
+            insertion_sort_block_move
+        ));
+    }
+
+    #[test]
+    fn block_move_matches_straight_insertion() {
+        let mut numbers: Vec<u16> = (1..100).map(|x| x % 13).collect();
+
+        for seed in 0..100 {
+            shuffle(&mut numbers, seed);
+            let mut expected = numbers.clone();
+
+            straight_insertion(&mut expected);
+            insertion_sort_block_move(&mut numbers);
+
+            assert_eq!(numbers, expected);
+        }
+    }
 }
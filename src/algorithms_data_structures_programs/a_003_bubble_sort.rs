@@ -130,27 +130,28 @@ mod tests {
 
     #[test]
     fn it_sorts_example() {
-        let mut array = vec![44, 55, 12, 42, 94, 18, 6, 67];
+        let array = [44, 55, 12, 42, 94, 18, 6, 67];
 
-        bubble_sort(&mut array);
-
-        assert!(is_sorted(&array));
+        assert_sorted_permutation(&array, bubble_sort);
     }
 
     #[test]
     fn fuzzy_test() {
-        extern crate rand;
-        use rand::prelude::SliceRandom;
-
-        let mut rng = rand::thread_rng();
         let mut numbers: Vec<u32> = (1..FUZZY_TEST_ITERATIONS).collect();
 
-        for _ in 0..100 {
-            numbers.shuffle(&mut rng);
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            shuffle(&mut numbers, seed);
 
             bubble_sort(&mut numbers);
 
             assert!(is_sorted(&numbers));
         }
     }
+
+    #[test]
+    fn it_is_stable_by_key() {
+        let mut pairs = keyed_pairs(200, 7, 1);
+
+        assert!(sort_and_check_stable_by_key(&mut pairs, bubble_sort));
+    }
 }
@@ -83,6 +83,8 @@
 //! [Donald E. Knuth]: https://www-cs-faculty.stanford.edu/~knuth/
 //! [D. L. Shell]: https://en.wikipedia.org/wiki/Donald_Shell
 
+use super::instrumentation::SortStats;
+
 /// Takes a mutable slice of comparable elements and sorts them in ASC order.
 pub fn shell_sort<T>(array: &mut [T])
 where
@@ -104,26 +106,77 @@ where
 
     // We want to start with the largest gap and work our way down to unity gap.
     for gap_index in (0..gaps_len).rev() {
-        let gap = gaps[gap_index];
-
-        // In standard straight insertion sort, we skipped first element. In
-        // this refined version we have to skip first `gap` elements. These are
-        // going to be accounted for thanks to the fact that we use
-        // `array[tracker - gap]`. (Note `- gap`.)
-        for index in gap..array.len() {
-            let mut tracker = index;
-
-            // We decrement the tracker until we hit sentinel mark or element
-            // on the right is larger/equal to it's group mate on the left.
-            while tracker >= gap && array[tracker] < array[tracker - gap] {
-                array.swap(tracker, tracker - gap);
+        gapped_insertion(array, gaps[gap_index]);
+    }
+}
 
-                tracker -= gap;
+/// Makes one pass of straight insertion over each chain of items which are
+/// `gap` positions apart. This is the building block of [`shell_sort`]. With
+/// gap of unity, it's a plain straight insertion sort.
+///
+/// [`shell_sort`]: fn.shell_sort.html
+pub fn gapped_insertion<T>(array: &mut [T], gap: usize)
+where
+    T: PartialEq + PartialOrd,
+{
+    gapped_insertion_counted(array, gap, &mut SortStats::default());
+}
+
+/// Does the work of [`gapped_insertion`] and adds the comparisons and swaps it
+/// performs to the stats. Both the plain and the instrumented sort share it,
+/// so that what is measured is exactly what is run.
+///
+/// [`gapped_insertion`]: fn.gapped_insertion.html
+fn gapped_insertion_counted<T>(array: &mut [T], gap: usize, stats: &mut SortStats)
+where
+    T: PartialEq + PartialOrd,
+{
+    // In standard straight insertion sort, we skipped first element. In
+    // this refined version we have to skip first `gap` elements. These are
+    // going to be accounted for thanks to the fact that we use
+    // `array[tracker - gap]`. (Note `- gap`.)
+    for index in gap..array.len() {
+        let mut tracker = index;
+
+        // We decrement the tracker until we hit sentinel mark or element
+        // on the right is larger/equal to it's group mate on the left.
+        while tracker >= gap {
+            stats.comparisons += 1;
+            if array[tracker] < array[tracker - gap] {
+                array.swap(tracker, tracker - gap);
+                stats.swaps += 1;
+            } else {
+                break;
             }
+
+            tracker -= gap;
         }
     }
 }
 
+/// Sorts the slice in the same way as [`shell_sort`], but with given sequence
+/// of gaps, and counts the operations it performs. This makes it possible to
+/// compare gap sequences empirically, e.g. `2^i - 1` against Knuth's
+/// `3h + 1`.
+///
+/// The gaps are expected in ascending order, the same way Wirth lists them,
+/// and they are applied from the largest one. The first gap should be unity,
+/// otherwise the array is not guaranteed to end up sorted.
+///
+/// [`shell_sort`]: fn.shell_sort.html
+pub fn shell_sort_instrumented<T>(array: &mut [T], gaps: &[usize]) -> SortStats
+where
+    T: PartialEq + PartialOrd,
+{
+    let mut stats = SortStats::default();
+
+    for &gap in gaps.iter().rev() {
+        gapped_insertion_counted(array, gap, &mut stats);
+    }
+
+    stats
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,27 +253,84 @@ mod tests {
 
     #[test]
     fn it_sorts_example() {
-        let mut array = vec![44, 55, 12, 42, 94, 18, 6, 67];
+        let array = [44, 55, 12, 42, 94, 18, 6, 67];
 
-        shell_sort(&mut array);
-
-        assert!(is_sorted(&array));
+        assert_sorted_permutation(&array, shell_sort);
     }
 
     #[test]
     fn fuzzy_test() {
-        extern crate rand;
-        use rand::prelude::SliceRandom;
-
-        let mut rng = rand::thread_rng();
         let mut numbers: Vec<u32> = (1..FUZZY_TEST_ITERATIONS).collect();
 
-        for _ in 0..100 {
-            numbers.shuffle(&mut rng);
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            shuffle(&mut numbers, seed);
 
             shell_sort(&mut numbers);
 
             assert!(is_sorted(&numbers));
         }
     }
+
+    #[test]
+    fn instrumented_compares_gap_sequences() {
+        let fixture: Vec<u32> = (0..100).map(|x| (x * 37) % 101).collect();
+
+        let mut powers_of_two = fixture.clone();
+        let powers_of_two_stats = shell_sort_instrumented(&mut powers_of_two, &[1, 3, 7, 15, 31]);
+
+        let mut knuth = fixture.clone();
+        let knuth_stats = shell_sort_instrumented(&mut knuth, &[1, 4, 13, 40]);
+
+        assert!(is_sorted(&powers_of_two));
+        assert!(is_sorted(&knuth));
+        assert_ne!(powers_of_two_stats.swaps, knuth_stats.swaps);
+    }
+
+    #[test]
+    fn instrumented_with_unity_gap_counts_insertion_sort() {
+        let mut array = vec![4, 3, 2, 1];
+
+        let stats = shell_sort_instrumented(&mut array, &[1]);
+
+        assert!(is_sorted(&array));
+        assert_eq!(stats.swaps, 6);
+        assert_eq!(stats.comparisons, 6);
+    }
+
+    #[test]
+    fn instrumented_counts_no_swaps_on_sorted_array() {
+        let mut array = vec![1, 2, 3, 4, 5, 6, 7, 8];
+
+        let stats = shell_sort_instrumented(&mut array, &[1, 3]);
+
+        assert_eq!(stats.swaps, 0);
+        assert_eq!(stats.comparisons, 12);
+    }
+
+    #[test]
+    fn it_is_unstable_on_larger_arrays() {
+        // Small arrays are sorted with unity gap only, which is straight
+        // insertion. Larger gaps move equal elements past one another.
+        let mut pairs = keyed_pairs(200, 7, 1);
+
+        assert!(!sort_and_check_stable_by_key(&mut pairs, shell_sort));
+    }
+
+    #[test]
+    fn gapped_insertion_sorts_chains() {
+        let mut array = vec![44, 55, 12, 42, 94, 18, 6, 67];
+
+        gapped_insertion(&mut array, 2);
+
+        assert_eq!(array, vec![6, 18, 12, 42, 44, 55, 94, 67]);
+    }
+
+    #[test]
+    fn gapped_insertion_with_gap_larger_than_array() {
+        let mut array = vec![3, 2, 1];
+
+        gapped_insertion(&mut array, 5);
+
+        assert_eq!(array, vec![3, 2, 1]);
+    }
 }
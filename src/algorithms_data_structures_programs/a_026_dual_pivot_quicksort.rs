@@ -0,0 +1,223 @@
+//! # Dual-pivot Quicksort
+//!
+//! Quicksort partitions the array around a single pivot into elements smaller
+//! and larger than the pivot, and sorts the two parts recursively. The
+//! dual-pivot variant by Vladimir Yaroslavskiy takes two pivots `P1 <= P2`
+//! and partitions the array into three parts in a single pass:
+//!
+//! ```text
+//! +----+-----------+----+----------------+----+-----------+
+//! | P1 |   < P1    | P1 | P1 <= .. <= P2 | P2 |   > P2    |
+//! +----+-----------+----+----------------+----+-----------+
+//!   ^ before         after partitioning             ^ before
+//! ```
+//!
+//! It doesn't do fewer comparisons than the classic Quicksort, but each pass
+//! splits the array into three parts instead of two, so the recursion is
+//! shallower and every element is read from memory fewer times. On modern
+//! hardware memory access rather than comparisons dominates, which is why
+//! Java sorts arrays of primitives with this algorithm.
+//!
+//! Quicksort degrades to quadratic time when partitioning makes little
+//! progress. With many duplicates the middle part would contain most of the
+//! array, so elements equal to either pivot are moved out of it before it's
+//! sorted. An array of equal elements is then sorted in a single pass.
+
+use super::a_002_straight_insertion::straight_insertion;
+
+/// Arrays up to this length are sorted by [`straight_insertion`], which is
+/// faster on them than further partitioning.
+///
+/// [`straight_insertion`]: ../a_002_straight_insertion/fn.straight_insertion.html
+pub const INSERTION_SORT_THRESHOLD: usize = 16;
+
+/// Takes a mutable slice of comparable elements and sorts them in ASC order.
+/// The sort is not stable.
+pub fn dual_pivot_quick_sort<T>(array: &mut [T])
+where
+    T: PartialEq + PartialOrd,
+{
+    if array.len() <= INSERTION_SORT_THRESHOLD {
+        return straight_insertion(array);
+    }
+
+    // Takes the elements at one and two thirds of the array as pivots rather
+    // than its ends, which would make sorted input the worst case.
+    let last = array.len() - 1;
+    let third = array.len() / 3;
+    array.swap(0, third);
+    array.swap(last, last - third);
+    if array[last] < array[0] {
+        array.swap(0, last);
+    }
+
+    // The pivots stay on the first and the last index during partitioning.
+    // Elements in `1..less` are smaller than P1, elements in `less..index`
+    // are between the pivots, elements in `greater + 1..last` are larger than
+    // P2. Elements in `index..=greater` haven't been visited yet.
+    let mut less = 1;
+    let mut greater = last - 1;
+    let mut index = 1;
+
+    while index <= greater {
+        if array[index] < array[0] {
+            array.swap(index, less);
+            less += 1;
+        } else if array[index] > array[last] {
+            // Skips elements at the end which already belong there, so that
+            // the one swapped in is not larger than P2.
+            while array[greater] > array[last] && index < greater {
+                greater -= 1;
+            }
+            array.swap(index, greater);
+            greater -= 1;
+
+            if array[index] < array[0] {
+                array.swap(index, less);
+                less += 1;
+            }
+        }
+
+        index += 1;
+    }
+
+    // Moves the pivots to their final positions between the parts.
+    let p1 = less - 1;
+    let p2 = greater + 1;
+    array.swap(0, p1);
+    array.swap(last, p2);
+
+    dual_pivot_quick_sort(&mut array[..p1]);
+    dual_pivot_quick_sort(&mut array[p2 + 1..]);
+
+    // If the pivots are equal, so is everything between them.
+    if array[p1] < array[p2] {
+        let (start, end) = squeeze_pivots(array, p1, p2);
+        dual_pivot_quick_sort(&mut array[start..end]);
+    }
+}
+
+/// Moves elements equal to P1 on index `p1` to the start of the middle part
+/// and elements equal to P2 on index `p2` to its end. Returns the range of the
+/// middle part which remains to be sorted.
+fn squeeze_pivots<T>(array: &mut [T], p1: usize, p2: usize) -> (usize, usize)
+where
+    T: PartialEq + PartialOrd,
+{
+    let mut start = p1 + 1;
+    let mut end = p2;
+    let mut index = start;
+
+    while index < end {
+        if array[index] == array[p1] {
+            array.swap(index, start);
+            start += 1;
+            index += 1;
+        } else if array[index] == array[p2] {
+            end -= 1;
+            array.swap(index, end);
+        } else {
+            index += 1;
+        }
+    }
+
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let mut array: Vec<u8> = Vec::new();
+
+        dual_pivot_quick_sort(&mut array);
+    }
+
+    #[test]
+    fn it_handles_array_of_one_element() {
+        let mut array = vec![4];
+
+        dual_pivot_quick_sort(&mut array);
+
+        assert_eq!(array[0], 4);
+    }
+
+    #[test]
+    fn it_sorts_ordered_array() {
+        let mut array: Vec<u32> = (0..100).collect();
+
+        dual_pivot_quick_sort(&mut array);
+
+        assert_eq!(array, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_sorts_reversed_array() {
+        let mut array: Vec<u32> = (0..100).rev().collect();
+
+        dual_pivot_quick_sort(&mut array);
+
+        assert_eq!(array, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_sorts_long_ordered_and_reversed_arrays() {
+        let mut ordered: Vec<u32> = (0..1_000_000).collect();
+        let mut reversed: Vec<u32> = (0..1_000_000).rev().collect();
+
+        dual_pivot_quick_sort(&mut ordered);
+        dual_pivot_quick_sort(&mut reversed);
+
+        assert!(is_sorted(&ordered));
+        assert_eq!(ordered, reversed);
+    }
+
+    #[test]
+    fn it_is_generic() {
+        let mut array: Vec<String> = (0..50).map(|x| format!("{:x}", x * 7919 % 50)).collect();
+
+        dual_pivot_quick_sort(&mut array);
+
+        assert!(is_sorted(&array));
+    }
+
+    #[test]
+    fn it_sorts_example() {
+        let array = [44, 55, 12, 42, 94, 18, 6, 67];
+
+        assert_sorted_permutation(&array, dual_pivot_quick_sort);
+    }
+
+    #[test]
+    fn it_sorts_equal_elements_in_linear_time() {
+        let mut array = vec![7u8; 1_000_000];
+
+        dual_pivot_quick_sort(&mut array);
+
+        assert!(array.iter().all(|&x| x == 7));
+    }
+
+    #[test]
+    fn it_sorts_few_distinct_values() {
+        let mut array: Vec<u8> = (0..1_000_000).map(|x| (x % 3) as u8).collect();
+        shuffle(&mut array, 1);
+
+        dual_pivot_quick_sort(&mut array);
+
+        assert!(is_sorted(&array));
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        let mut numbers: Vec<u32> = (0..500).map(|x| x % 150).collect();
+
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            shuffle(&mut numbers, seed);
+
+            assert_sorted_permutation(&numbers, dual_pivot_quick_sort);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! # Binary heap
+//!
+//! A heap is a binary tree in which every node precedes its children. The
+//! root is therefore the first element of all. The tree is complete: all its
+//! levels are full except for the last one, which is filled from the left.
+//! Such a tree is stored in an array without any pointers. Children of the
+//! node on index `i` are on indices `2i + 1` and `2i + 2`.
+//!
+//! ```text
+//!             9
+//!           /   \
+//!          7     8
+//!         / \   /
+//!        3   5 6             [9, 7, 8, 3, 5, 6]
+//! ```
+//!
+//! A new element is appended to the end of the array and then _sifted up_:
+//! swapped with its parent for as long as it precedes it. The root is removed
+//! by replacing it with the last element, which is then _sifted down_:
+//! swapped with the preceding one of its children for as long as it doesn't
+//! precede both of them. Both operations walk a single path between the root
+//! and a leaf, therefore they run in O(log n).
+//!
+//! What "precedes" means is decided by a comparator given at construction.
+//! The element which is the greatest according to the comparator is on the
+//! root. Comparing naturally gives a max-heap, comparing in reverse gives a
+//! min-heap, and comparing derived keys gives a priority queue.
+
+use std::cmp::Ordering;
+
+/// Binary heap ordered by a comparator. The greatest element according to the
+/// comparator is popped first.
+pub struct Heap<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    elements: Vec<T>,
+    comparator: F,
+}
+
+impl<T, F> Heap<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    pub fn new(comparator: F) -> Self {
+        Heap {
+            elements: Vec::new(),
+            comparator,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the greatest element without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.elements.first()
+    }
+
+    pub fn push(&mut self, element: T) {
+        self.elements.push(element);
+
+        let last = self.elements.len() - 1;
+        self.sift_up(last);
+    }
+
+    /// Removes and returns the greatest element.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.elements.is_empty() {
+            return None;
+        }
+
+        let last = self.elements.len() - 1;
+        self.elements.swap(0, last);
+        let top = self.elements.pop();
+        self.sift_down(0);
+
+        top
+    }
+
+    /// Whether the element on index `a` should be above the one on index `b`.
+    fn precedes(&self, a: usize, b: usize) -> bool {
+        (self.comparator)(&self.elements[a], &self.elements[b]) == Ordering::Greater
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.precedes(index, parent) {
+                return;
+            }
+
+            self.elements.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = index * 2 + 1;
+            let right = left + 1;
+            let mut first = index;
+
+            if left < self.len() && self.precedes(left, first) {
+                first = left;
+            }
+            if right < self.len() && self.precedes(right, first) {
+                first = right;
+            }
+
+            if first == index {
+                return;
+            }
+
+            self.elements.swap(index, first);
+            index = first;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_heap() {
+        let mut heap = Heap::new(|a: &u8, b: &u8| a.cmp(b));
+
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn it_is_max_heap_with_natural_order() {
+        let mut heap = Heap::new(|a: &u32, b: &u32| a.cmp(b));
+        let mut numbers: Vec<u32> = (0..100).collect();
+        shuffle(&mut numbers, 1);
+
+        for number in numbers {
+            heap.push(number);
+        }
+
+        assert_eq!(heap.len(), 100);
+        assert_eq!(heap.peek(), Some(&99));
+        for expected in (0..100).rev() {
+            assert_eq!(heap.pop(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn it_is_min_heap_with_reversed_order() {
+        let mut heap = Heap::new(|a: &i32, b: &i32| b.cmp(a));
+
+        for &number in &[5, -3, 8, 0, 8] {
+            heap.push(number);
+        }
+        assert!(is_min_heap(&heap.elements));
+
+        let popped: Vec<_> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(popped, vec![-3, 0, 5, 8, 8]);
+    }
+
+    #[test]
+    fn it_orders_by_derived_key() {
+        let mut heap = Heap::new(|a: &(&str, u32), b: &(&str, u32)| a.1.cmp(&b.1));
+
+        heap.push(("low", 1));
+        heap.push(("high", 10));
+        heap.push(("medium", 5));
+
+        assert_eq!(heap.pop(), Some(("high", 10)));
+        assert_eq!(heap.pop(), Some(("medium", 5)));
+        assert_eq!(heap.pop(), Some(("low", 1)));
+    }
+}
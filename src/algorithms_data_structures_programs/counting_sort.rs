@@ -0,0 +1,257 @@
+//! # Counting sort
+//!
+//! Sorting algorithms which compare keys cannot do better than `O(n log n)`.
+//! Counting sort does not compare keys at all. Instead it counts how many times
+//! each value occurs and then writes the values back in ascending order. It
+//! runs in `O(n + k)` time and `O(k)` space, where `k` is the range of the
+//! values, i.e. the difference between the largest and the smallest value.
+//!
+//! ```text
+//! array:  3, 1, 3, 0, 1, 3
+//!
+//! value:  0, 1, 2, 3
+//! count:  1, 2, 0, 3
+//!
+//! sorted: 0, 1, 1, 3, 3, 3
+//! ```
+//!
+//! The catch is the `k`. Sorting two numbers `0` and `u32::MAX` would need
+//! over four billion counters. Therefore the sort refuses to allocate more than
+//! [`MAX_RANGE`] counters and returns an error instead. Caller can then fall
+//! back to a comparison sort.
+//!
+//! [`MAX_RANGE`]: constant.MAX_RANGE.html
+
+use std::error::Error;
+use std::fmt;
+
+/// The largest range of values the sort allocates counters for. With 8 byte
+/// counters, this is 128 MiB of memory.
+pub const MAX_RANGE: u64 = 1 << 24;
+
+/// Returned when the values in the array span too large a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountingSortError {
+    /// How many counters the sort would need.
+    pub range: u64,
+    /// How many counters the sort is willing to allocate.
+    pub threshold: u64,
+}
+
+impl fmt::Display for CountingSortError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "range of values {} exceeds the threshold {}",
+            self.range, self.threshold
+        )
+    }
+}
+
+impl Error for CountingSortError {}
+
+/// Sorts the slice of integers in ASC order, unless the values span more than
+/// [`MAX_RANGE`] distinct values. In such case the slice is left untouched.
+///
+/// [`MAX_RANGE`]: constant.MAX_RANGE.html
+pub fn counting_sort(array: &mut [u32]) -> Result<(), CountingSortError> {
+    let (min, range) = match value_range(array)? {
+        Some(bounds) => bounds,
+        // Empty array is already sorted.
+        None => return Ok(()),
+    };
+
+    // Counter on index `i` belongs to value `min + i`.
+    let mut counts = vec![0usize; range];
+    for &value in array.iter() {
+        counts[(value - min) as usize] += 1;
+    }
+
+    write_counts(array, min, &counts);
+
+    Ok(())
+}
+
+/// Returns the smallest value of the array and how many counters the values
+/// need, or `None` if the array is empty. Fails if the values span more than
+/// [`MAX_RANGE`] distinct values.
+///
+/// [`MAX_RANGE`]: constant.MAX_RANGE.html
+pub(super) fn value_range(array: &[u32]) -> Result<Option<(u32, usize)>, CountingSortError> {
+    let (min, max) = match (array.iter().min(), array.iter().max()) {
+        (Some(&min), Some(&max)) => (min, max),
+        _ => return Ok(None),
+    };
+
+    // Counting in u64 because the range of u32 values doesn't fit into u32.
+    let range = u64::from(max - min) + 1;
+    if range > MAX_RANGE {
+        return Err(CountingSortError {
+            range,
+            threshold: MAX_RANGE,
+        });
+    }
+
+    Ok(Some((min, range as usize)))
+}
+
+/// Overwrites the array with values in ascending order, each repeated as many
+/// times as its counter says. Counter on index `i` belongs to value `min + i`
+/// and the counters must add up to the length of the array.
+pub(super) fn write_counts(array: &mut [u32], min: u32, counts: &[usize]) {
+    let mut index = 0;
+    for (offset, &count) in counts.iter().enumerate() {
+        for slot in &mut array[index..index + count] {
+            *slot = min + offset as u32;
+        }
+
+        index += count;
+    }
+}
+
+/// Sorts the pairs in ASC order by their key. Pairs with equal keys keep their
+/// relative order, i.e. the sort is stable. Unlike [`counting_sort`], the
+/// values can't simply be written back from the counts, because each key
+/// carries its own value. Instead, prefix sums of the counts give the index at
+/// which each key starts in the output, and the pairs are placed there one by
+/// one in input order. This is the building block of radix sort on records.
+///
+/// Only keys between the smallest and the largest one are counted. Their
+/// range never exceeds [`MAX_RANGE`], because `u16` keys span fewer values.
+///
+/// [`counting_sort`]: fn.counting_sort.html
+/// [`MAX_RANGE`]: constant.MAX_RANGE.html
+pub fn counting_sort_pairs<V>(pairs: &mut Vec<(u16, V)>)
+where
+    V: Clone,
+{
+    let keys = pairs.iter().map(|&(key, _)| key);
+    let (min, max) = match (keys.clone().min(), keys.max()) {
+        (Some(min), Some(max)) => (min, max),
+        // Empty array is already sorted.
+        _ => return,
+    };
+
+    // Counter on index `i` belongs to key `min + i`.
+    let mut counts = vec![0usize; usize::from(max - min) + 1];
+    for (key, _) in pairs.iter() {
+        counts[usize::from(key - min)] += 1;
+    }
+
+    // Turns the counts into the index of the first slot of each key.
+    let mut next_slot = 0;
+    for count in counts.iter_mut() {
+        let slots = *count;
+        *count = next_slot;
+        next_slot += slots;
+    }
+
+    let mut sorted = vec![None; pairs.len()];
+    for pair in pairs.drain(..) {
+        let slot = &mut counts[usize::from(pair.0 - min)];
+        sorted[*slot] = Some(pair);
+        *slot += 1;
+    }
+
+    pairs.extend(sorted.into_iter().map(|pair| pair.unwrap()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let mut array: Vec<u32> = Vec::new();
+
+        assert_eq!(counting_sort(&mut array), Ok(()));
+    }
+
+    #[test]
+    fn it_sorts_example() {
+        let mut array = vec![3, 1, 3, 0, 1, 3];
+
+        assert_eq!(counting_sort(&mut array), Ok(()));
+        assert_eq!(array, vec![0, 1, 1, 3, 3, 3]);
+
+        assert_sorted_permutation(&[3, 1, 3, 0, 1, 3], |array| counting_sort(array).unwrap());
+    }
+
+    #[test]
+    fn it_sorts_values_far_from_zero() {
+        let mut array = vec![u32::MAX, u32::MAX - 5, u32::MAX - 2];
+
+        assert_eq!(counting_sort(&mut array), Ok(()));
+        assert_eq!(array, vec![u32::MAX - 5, u32::MAX - 2, u32::MAX]);
+    }
+
+    #[test]
+    fn it_refuses_too_large_range() {
+        let mut array = vec![u32::MAX, 0];
+
+        let error = counting_sort(&mut array).unwrap_err();
+
+        assert_eq!(error.range, 1 << 32);
+        assert_eq!(error.threshold, MAX_RANGE);
+        assert_eq!(array, vec![u32::MAX, 0]);
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        let mut numbers: Vec<u32> = (1..FUZZY_TEST_ITERATIONS).map(|x| x % 10).collect();
+
+        for seed in 0..100 {
+            shuffle(&mut numbers, seed);
+
+            assert_eq!(counting_sort(&mut numbers), Ok(()));
+
+            assert!(is_sorted(&numbers));
+        }
+    }
+
+    #[test]
+    fn it_sorts_pairs_stably() {
+        let mut pairs = vec![(2, 'a'), (1, 'b'), (2, 'c')];
+
+        counting_sort_pairs(&mut pairs);
+
+        assert_eq!(pairs, vec![(1, 'b'), (2, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn it_sorts_empty_pairs() {
+        let mut pairs: Vec<(u16, char)> = Vec::new();
+
+        counting_sort_pairs(&mut pairs);
+
+        assert!(pairs.is_empty());
+    }
+
+    #[test]
+    fn it_sorts_pairs_with_extreme_keys() {
+        let mut pairs = vec![(u16::MAX, 0), (0, 1), (u16::MAX, 2), (0, 3)];
+
+        counting_sort_pairs(&mut pairs);
+
+        assert_eq!(pairs, vec![(0, 1), (0, 3), (u16::MAX, 0), (u16::MAX, 2)]);
+    }
+
+    #[test]
+    fn fuzzy_test_pairs() {
+        let mut keys: Vec<u16> = (1..FUZZY_TEST_ITERATIONS)
+            .map(|x| (x % 10) as u16)
+            .collect();
+
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            shuffle(&mut keys, seed);
+            let mut pairs: Vec<_> = keys.iter().copied().zip(0..).collect();
+            let mut expected = pairs.clone();
+            expected.sort_by_key(|&(key, _)| key);
+
+            counting_sort_pairs(&mut pairs);
+
+            assert_eq!(pairs, expected);
+        }
+    }
+}
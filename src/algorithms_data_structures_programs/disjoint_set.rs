@@ -0,0 +1,156 @@
+//! # Disjoint set
+//!
+//! Also known as _union-find_. The structure keeps track of a partition of
+//! elements `0..n` into disjoint sets. Initially, each element is in a set of
+//! its own. Two operations are supported:
+//! - **find** returns a representative of the set an element is in, so that
+//!   two elements are in the same set if and only if they have the same
+//!   representative;
+//! - **union** merges the sets two elements are in.
+//!
+//! Each set is a tree of elements pointing to their parents. The root of the
+//! tree is the representative of the set. Two techniques keep the trees
+//! shallow:
+//! - _union by rank_ attaches the shallower tree under the root of the deeper
+//!   one, so that the depth of a tree grows only when two trees of the same
+//!   depth are merged;
+//! - _path compression_ points every element visited by **find** directly to
+//!   the root.
+//!
+//! Together they make both operations run in amortized time which is, for all
+//! practical purposes, constant.
+//!
+//! ```text
+//! union(1, 2), union(3, 4), union(2, 4)
+//!
+//!         1                 1
+//!        / \    find(4)    /|\
+//!       2   3   ------>   2 3 4
+//!           |
+//!           4
+//! ```
+
+/// Partition of elements `0..n` into disjoint sets.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    // Upper bound on the depth of the tree rooted in the element.
+    ranks: Vec<u8>,
+}
+
+impl DisjointSet {
+    /// Creates a partition of `n` elements into `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+        }
+    }
+
+    /// Returns the representative of the set the element is in.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Path compression. Walks the path again and points each element on
+        // it directly to the root.
+        let mut element = element;
+        while self.parents[element] != root {
+            let parent = self.parents[element];
+            self.parents[element] = root;
+            element = parent;
+        }
+
+        root
+    }
+
+    /// Merges the sets the two elements are in. Returns `false` if they
+    /// already were in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let a = self.find(a);
+        let b = self.find(b);
+
+        if a == b {
+            return false;
+        }
+
+        // Union by rank. The root of the shallower tree is attached under the
+        // root of the deeper tree, which keeps its depth.
+        if self.ranks[a] < self.ranks[b] {
+            self.parents[a] = b;
+        } else if self.ranks[a] > self.ranks[b] {
+            self.parents[b] = a;
+        } else {
+            self.parents[b] = a;
+            self.ranks[a] += 1;
+        }
+
+        true
+    }
+
+    /// Returns whether the two elements are in the same set.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_starts_with_singletons() {
+        let mut set = DisjointSet::new(3);
+
+        assert_eq!(set.find(0), 0);
+        assert_eq!(set.find(1), 1);
+        assert_eq!(set.find(2), 2);
+        assert!(!set.connected(0, 1));
+    }
+
+    #[test]
+    fn it_partitions_elements() {
+        let mut set = DisjointSet::new(8);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(set.union(5, 6));
+        assert!(!set.union(0, 2));
+
+        // Expected partition is {0, 1, 2, 3}, {4}, {5, 6}, {7}.
+        let partition = [0, 0, 0, 0, 4, 5, 5, 7];
+        for a in 0..8 {
+            for b in 0..8 {
+                assert_eq!(set.connected(a, b), partition[a] == partition[b]);
+            }
+        }
+    }
+
+    #[test]
+    fn find_is_consistent_after_compression() {
+        let mut set = DisjointSet::new(100);
+        for element in 1..100 {
+            set.union(element - 1, element);
+        }
+
+        let root = set.find(99);
+        for element in 0..100 {
+            assert_eq!(set.find(element), root);
+            assert_eq!(set.parents[element], root);
+        }
+    }
+
+    #[test]
+    fn union_by_rank_keeps_trees_shallow() {
+        let mut set = DisjointSet::new(1024);
+        for element in 1..1024 {
+            set.union(0, element);
+        }
+
+        // Every element was attached to the same root of rank one.
+        assert_eq!(set.ranks.iter().max(), Some(&1));
+    }
+}
@@ -0,0 +1,123 @@
+//! # External merge sort
+//!
+//! When data don't fit into memory, they can't be sorted by any of the
+//! methods which access the whole array at once. Instead, they are read in
+//! chunks small enough to be sorted in memory. Each sorted chunk is written
+//! back to storage as a run. Then all runs are merged at once in a single
+//! pass, which only ever needs to hold the first remaining element of each
+//! run in memory.
+//!
+//! The merge of K runs repeatedly picks the smallest of K candidates. Keeping
+//! the candidates in a min-[`Heap`] makes each pick O(log K) instead of O(K),
+//! so merging N elements takes O(N log K).
+//!
+//! ```text
+//! [5, 1, 9]  [7, 3]  [8, 2, 6]      chunks
+//! [1, 5, 9]  [3, 7]  [2, 6, 8]      sorted runs
+//! [1, 2, 3, 5, 6, 7, 8, 9]          merged
+//! ```
+//!
+//! Here the chunks are vectors in memory, which models the storage.
+//!
+//! [`Heap`]: ../binary_heap/struct.Heap.html
+
+use super::a_005_shell_sort::shell_sort;
+use super::binary_heap::Heap;
+use std::cmp::Ordering;
+
+/// Sorts each chunk with [`shell_sort`] and merges the sorted runs into one
+/// sorted vector. Of equal elements, those from earlier chunks come first.
+/// Equal elements within one chunk may be reordered, as Shell sort is not
+/// stable.
+///
+/// [`shell_sort`]: ../a_005_shell_sort/fn.shell_sort.html
+pub fn external_merge_sort<T>(chunks: Vec<Vec<T>>) -> Vec<T>
+where
+    T: PartialOrd,
+{
+    let total_len = chunks.iter().map(Vec::len).sum();
+
+    let mut runs: Vec<_> = chunks
+        .into_iter()
+        .map(|mut chunk| {
+            shell_sort(&mut chunk);
+            chunk.into_iter()
+        })
+        .collect();
+
+    // The heap pops its greatest element, so the comparator is reversed to
+    // pop the smallest one. Each candidate remembers which run it came from.
+    let mut candidates = Heap::new(|a: &(T, usize), b: &(T, usize)| {
+        b.0.partial_cmp(&a.0)
+            .unwrap_or(Ordering::Equal)
+            .then(b.1.cmp(&a.1))
+    });
+    for (run_index, run) in runs.iter_mut().enumerate() {
+        if let Some(element) = run.next() {
+            candidates.push((element, run_index));
+        }
+    }
+
+    let mut merged = Vec::with_capacity(total_len);
+    while let Some((element, run_index)) = candidates.pop() {
+        merged.push(element);
+
+        if let Some(next) = runs[run_index].next() {
+            candidates.push((next, run_index));
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_no_chunks() {
+        let chunks: Vec<Vec<u8>> = Vec::new();
+
+        assert!(external_merge_sort(chunks).is_empty());
+        assert!(external_merge_sort(vec![Vec::<u8>::new(), Vec::new()]).is_empty());
+    }
+
+    #[test]
+    fn it_sorts_example() {
+        let chunks = vec![vec![5, 1, 9], vec![7, 3], vec![8, 2, 6]];
+
+        assert_eq!(external_merge_sort(chunks), vec![1, 2, 3, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn earlier_chunks_win_ties() {
+        let chunks = vec![
+            vec![ByKey(2, 0), ByKey(1, 1)],
+            vec![ByKey(1, 2)],
+            vec![ByKey(2, 3), ByKey(0, 4)],
+        ];
+
+        let order: Vec<_> = external_merge_sort(chunks)
+            .iter()
+            .map(|item| item.1)
+            .collect();
+
+        assert_eq!(order, vec![4, 1, 2, 0, 3]);
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        let mut numbers: Vec<u32> = (0..100).collect();
+        for seed in 0..FUZZY_TEST_ITERATIONS as u64 {
+            shuffle(&mut numbers, seed);
+            let chunk_len = 1 + seed as usize % 17;
+            let chunks: Vec<Vec<u32>> = numbers.chunks(chunk_len).map(<[u32]>::to_vec).collect();
+
+            let sorted = external_merge_sort(chunks);
+
+            assert!(is_sorted(&sorted));
+            assert_eq!(multiset_diff(&sorted, &numbers), (vec![], vec![]));
+        }
+    }
+}
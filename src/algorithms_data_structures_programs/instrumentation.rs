@@ -0,0 +1,254 @@
+//! # Instrumentation
+//!
+//! Niklaus Wirth analyses sorting algorithms by counting two operations:
+//! comparisons of keys and moves of items. This module provides means of
+//! measuring them so that the analysis can be checked empirically.
+//!
+//! Sorts in this crate are generic over [`PartialOrd`]. To count comparisons of
+//! any of them without instrumenting each sort separately, the elements are
+//! wrapped in [`Compared`], which delegates ordering to a [`Comparator`]. The
+//! [`CountingComparator`] then counts how many times it has been asked.
+//!
+//! [`PartialOrd`]: https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html
+//! [`Compared`]: struct.Compared.html
+//! [`Comparator`]: trait.Comparator.html
+//! [`CountingComparator`]: struct.CountingComparator.html
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+/// Counts of operations a sort performed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SortStats {
+    /// How many times two elements were compared.
+    pub comparisons: u64,
+    /// How many times two elements exchanged their positions.
+    pub swaps: u64,
+}
+
+/// Decides the order of two elements. Any closure `Fn(&T, &T) -> Ordering` is
+/// a comparator.
+pub trait Comparator<T> {
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T, F> Comparator<T> for F
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// Wraps a comparator and counts how many times it has been called. The
+/// counter is a [`Cell`] so that the comparator can be shared by reference
+/// among all compared elements.
+///
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+pub struct CountingComparator<C> {
+    comparator: C,
+    comparisons: Cell<u64>,
+}
+
+impl<C> CountingComparator<C> {
+    pub fn new(comparator: C) -> Self {
+        CountingComparator {
+            comparator,
+            comparisons: Cell::new(0),
+        }
+    }
+
+    /// How many comparisons have been made so far.
+    pub fn comparisons(&self) -> u64 {
+        self.comparisons.get()
+    }
+}
+
+impl<T, C> Comparator<T> for CountingComparator<C>
+where
+    C: Comparator<T>,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.comparisons.set(self.comparisons.get() + 1);
+
+        self.comparator.compare(a, b)
+    }
+}
+
+/// Comparator which reverses the order of another one, the same way as
+/// [`Reverse`] reverses the order of a value. Elements which are equal stay
+/// equal, so a stable sort keeps their relative order.
+///
+/// [`Reverse`]: https://doc.rust-lang.org/std/cmp/struct.Reverse.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Reversed<C>(pub C);
+
+impl<T, C> Comparator<T> for Reversed<C>
+where
+    C: Comparator<T>,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.0.compare(b, a)
+    }
+}
+
+/// An element which is ordered by a comparator instead of its own ordering.
+pub struct Compared<'c, T, C> {
+    value: T,
+    comparator: &'c C,
+}
+
+impl<'c, T, C> Compared<'c, T, C> {
+    pub fn new(value: T, comparator: &'c C) -> Self {
+        Compared { value, comparator }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<'c, T, C> PartialEq for Compared<'c, T, C>
+where
+    C: Comparator<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.comparator.compare(&self.value, &other.value) == Ordering::Equal
+    }
+}
+
+impl<'c, T, C> PartialOrd for Compared<'c, T, C>
+where
+    C: Comparator<T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.comparator.compare(&self.value, &other.value))
+    }
+}
+
+/// Wraps each value so that it's ordered by given comparator.
+pub fn compared<'c, T, C>(
+    values: impl IntoIterator<Item = T>,
+    comparator: &'c C,
+) -> Vec<Compared<'c, T, C>> {
+    values
+        .into_iter()
+        .map(|value| Compared::new(value, comparator))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms_data_structures_programs::a_003_bubble_sort::bubble_sort;
+    use crate::algorithms_data_structures_programs::a_005_shell_sort::shell_sort;
+    use crate::test_helpers::shuffle;
+
+    #[test]
+    fn it_counts_bubble_sort_comparisons() {
+        let comparator = CountingComparator::new(|a: &u32, b: &u32| a.cmp(b));
+        let mut array = compared((1..=100).rev(), &comparator);
+
+        bubble_sort(&mut array);
+
+        // Bubblesort compares each pair of positions once: n(n - 1) / 2.
+        assert_eq!(comparator.comparisons(), 4950);
+
+        let array: Vec<u32> = array.into_iter().map(Compared::into_inner).collect();
+        assert_eq!(array, (1..=100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_sorts_by_comparator() {
+        let comparator = |a: &(u8, char), b: &(u8, char)| a.1.cmp(&b.1);
+        let mut array = compared(vec![(1, 'c'), (2, 'a'), (3, 'b')], &comparator);
+
+        bubble_sort(&mut array);
+
+        assert_eq!(*array[0].value(), (2, 'a'));
+        assert_eq!(*array[1].value(), (3, 'b'));
+        assert_eq!(*array[2].value(), (1, 'c'));
+    }
+
+    #[test]
+    fn it_starts_with_no_comparisons() {
+        let comparator = CountingComparator::new(|a: &u32, b: &u32| a.cmp(b));
+
+        assert_eq!(comparator.comparisons(), 0);
+        assert_eq!(comparator.compare(&1, &2), Ordering::Less);
+        assert_eq!(comparator.comparisons(), 1);
+    }
+
+    #[test]
+    fn reversed_comparator_sorts_descending_and_stable() {
+        let by_key = |a: &(u8, char), b: &(u8, char)| a.0.cmp(&b.0);
+        let comparator = Reversed(by_key);
+        let values = vec![(1, 'a'), (3, 'b'), (2, 'c'), (3, 'd'), (1, 'e')];
+        let mut array = compared(values, &comparator);
+
+        bubble_sort(&mut array);
+
+        let array: Vec<_> = array.into_iter().map(Compared::into_inner).collect();
+        assert_eq!(
+            array,
+            vec![(3, 'b'), (3, 'd'), (2, 'c'), (1, 'a'), (1, 'e')]
+        );
+    }
+
+    #[test]
+    fn reversed_twice_is_original_order() {
+        let natural = |a: &u32, b: &u32| a.cmp(b);
+
+        assert_eq!(Reversed(natural).compare(&1, &2), Ordering::Greater);
+        assert_eq!(Reversed(natural).compare(&2, &2), Ordering::Equal);
+        assert_eq!(Reversed(Reversed(natural)).compare(&1, &2), Ordering::Less);
+    }
+
+    type NaturalOrder = CountingComparator<fn(&u32, &u32) -> Ordering>;
+
+    /// Counts comparisons the sort makes on a shuffled array of given length.
+    fn comparisons_to_sort<F>(len: u32, sort: F) -> u64
+    where
+        F: for<'c> Fn(&mut [Compared<'c, u32, NaturalOrder>]),
+    {
+        let mut numbers: Vec<u32> = (0..len).collect();
+        shuffle(&mut numbers, u64::from(len));
+
+        let comparator: NaturalOrder = CountingComparator::new(|a, b| a.cmp(b));
+        let mut array = compared(numbers, &comparator);
+        sort(&mut array);
+
+        comparator.comparisons()
+    }
+
+    /// Checks the complexity classes claimed in documentation of the sorts.
+    /// When the input doubles, an `O(n^2)` sort does four times as many
+    /// comparisons, while an `O(n log n)` sort does slightly over twice as
+    /// many. Shellsort, at `n^1.2`, falls in between, close to the latter.
+    ///
+    /// Tolerance bands: quadratic growth must be in `3.5..=4.5`, subquadratic
+    /// growth must be in `2.0..=3.0`. The bands are wide enough to absorb the
+    /// lower order terms and the randomness of the input, yet they don't
+    /// overlap.
+    #[test]
+    fn complexity_regression() {
+        let (n, n2) = (1000, 2000);
+
+        let bubble = comparisons_to_sort(n2, |a| bubble_sort(a)) as f64
+            / comparisons_to_sort(n, |a| bubble_sort(a)) as f64;
+        assert!(
+            (3.5..=4.5).contains(&bubble),
+            "bubble sort grew {}x",
+            bubble
+        );
+
+        let shell = comparisons_to_sort(n2, |a| shell_sort(a)) as f64
+            / comparisons_to_sort(n, |a| shell_sort(a)) as f64;
+        assert!((2.0..=3.0).contains(&shell), "shell sort grew {}x", shell);
+    }
+}
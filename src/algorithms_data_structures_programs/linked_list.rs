@@ -0,0 +1,253 @@
+//! # Linked list
+//!
+//! A singly linked list keeps each element in its own node together with a
+//! pointer to the next node. Unlike an array it cannot be indexed in constant
+//! time, which rules out most sorting methods that jump across the sequence.
+//!
+//! Merge sort on the other hand only ever walks its input sequentially. A list
+//! is split into two halves by cutting the link in its middle, both halves are
+//! sorted recursively and then merged by re-linking nodes. No element is ever
+//! copied or moved in memory and no auxiliary storage is needed, which is why
+//! merge sort is the sort of choice for lists.
+//!
+//! ```text
+//! [3] -> [1] -> [2] -> [0]
+//! [3] -> [1]    [2] -> [0]      split
+//! [1] -> [3]    [0] -> [2]      sort halves
+//! [0] -> [1] -> [2] -> [3]      merge
+//! ```
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+/// Singly linked list of owned nodes.
+pub struct LinkedList<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+/// Iterator over references to the values of a [`LinkedList`].
+///
+/// [`LinkedList`]: struct.LinkedList.html
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        LinkedList { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { value, next }));
+        self.len += 1;
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T> LinkedList<T>
+where
+    T: PartialOrd,
+{
+    /// Sorts the list in place by re-linking its nodes. The sort is stable and
+    /// runs in time O(n log n).
+    pub fn merge_sort(&mut self) {
+        let head = self.head.take();
+        self.head = merge_sort(head, self.len);
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    // Drops the nodes one by one, as the default recursive drop would overflow
+    // the stack on long lists.
+    fn drop(&mut self) {
+        let mut link = self.head.take();
+        while let Some(mut node) = link {
+            link = node.next.take();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+/// Sorts the list of given length starting at `head` and returns its new head.
+fn merge_sort<T>(mut head: Link<T>, len: usize) -> Link<T>
+where
+    T: PartialOrd,
+{
+    if len < 2 {
+        return head;
+    }
+
+    let middle = len / 2;
+    let tail = split_off(&mut head, middle);
+
+    merge(merge_sort(head, middle), merge_sort(tail, len - middle))
+}
+
+/// Cuts the list after `at` nodes and returns the detached remainder.
+fn split_off<T>(head: &mut Link<T>, at: usize) -> Link<T> {
+    let mut link = head;
+    for _ in 0..at {
+        match link {
+            Some(node) => link = &mut node.next,
+            None => return None,
+        }
+    }
+
+    link.take()
+}
+
+/// Merges two sorted lists into one. On ties the node from the `left` list
+/// goes first, which keeps the sort stable.
+fn merge<T>(mut left: Link<T>, mut right: Link<T>) -> Link<T>
+where
+    T: PartialOrd,
+{
+    let mut merged = None;
+    let mut tail = &mut merged;
+
+    loop {
+        let next = match (left.take(), right.take()) {
+            (Some(mut l), Some(r)) if l.value <= r.value => {
+                left = l.next.take();
+                right = Some(r);
+                l
+            }
+            (Some(l), Some(mut r)) => {
+                right = r.next.take();
+                left = Some(l);
+                r
+            }
+            (rest, None) | (None, rest) => {
+                *tail = rest;
+                return merged;
+            }
+        };
+
+        tail = &mut tail.insert(next).next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    fn list_of(values: &[u32]) -> LinkedList<u32> {
+        let mut list = LinkedList::new();
+        for &value in values.iter().rev() {
+            list.push_front(value);
+        }
+
+        list
+    }
+
+    #[test]
+    fn it_pushes_to_front() {
+        let mut list = LinkedList::new();
+        assert!(list.is_empty());
+
+        list.push_front(1);
+        list.push_front(2);
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2, &1]);
+    }
+
+    #[test]
+    fn it_sorts_reversed_sequence() {
+        let mut list = LinkedList::new();
+        for value in 0..10 {
+            list.push_front(value);
+        }
+
+        list.merge_sort();
+
+        assert_eq!(list.len(), 10);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn it_handles_small_lists() {
+        let mut empty: LinkedList<u32> = LinkedList::new();
+        empty.merge_sort();
+        assert_eq!(empty.iter().next(), None);
+
+        let mut single = list_of(&[5]);
+        single.merge_sort();
+        assert_eq!(single.iter().collect::<Vec<_>>(), vec![&5]);
+    }
+
+    #[test]
+    fn it_is_stable_by_key() {
+        let mut list = LinkedList::new();
+        for (index, &key) in [2, 1, 2, 0, 1, 2].iter().enumerate().rev() {
+            list.push_front(ByKey(key, index));
+        }
+
+        list.merge_sort();
+
+        let order: Vec<_> = list.iter().map(|item| item.1).collect();
+        assert_eq!(order, vec![3, 1, 4, 0, 2, 5]);
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        let mut numbers: Vec<u32> = (0..100).collect();
+        for seed in 0..FUZZY_TEST_ITERATIONS as u64 {
+            shuffle(&mut numbers, seed);
+            let mut list = list_of(&numbers);
+
+            list.merge_sort();
+
+            let sorted: Vec<_> = list.iter().copied().collect();
+            assert!(is_sorted(&sorted));
+            assert_eq!(sorted.len(), numbers.len());
+        }
+    }
+
+    #[test]
+    fn it_drops_long_lists() {
+        let mut list = LinkedList::new();
+        for value in 0..1_000_000 {
+            list.push_front(value);
+        }
+    }
+}
@@ -3,3 +3,12 @@ pub mod a_002_straight_insertion;
 pub mod a_003_bubble_sort;
 pub mod a_004_shaker_sort;
 pub mod a_005_shell_sort;
+pub mod a_026_dual_pivot_quicksort;
+pub mod binary_heap;
+pub mod counting_sort;
+pub mod disjoint_set;
+pub mod external_merge_sort;
+pub mod instrumentation;
+pub mod linked_list;
+pub mod parallel_counting_sort;
+pub mod sorting_network;
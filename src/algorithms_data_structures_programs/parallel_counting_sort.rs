@@ -0,0 +1,192 @@
+//! # Parallel counting sort
+//!
+//! [Counting sort] spends most of its time counting the values. The counts of
+//! one part of the array don't depend on the rest of it, so the array can be
+//! split into chunks which are counted on separate threads. The histograms of
+//! the chunks are then summed and the sorted values written back the same way
+//! as in the sequential sort.
+//!
+//! ```text
+//! array:      3, 1, 3, 0 | 1, 3
+//!
+//! value:      0, 1, 2, 3
+//! thread 1:   1, 1, 0, 2
+//! thread 2:   0, 1, 0, 1
+//! merged:     1, 2, 0, 3
+//!
+//! sorted:     0, 1, 1, 3, 3, 3
+//! ```
+//!
+//! The price is memory. Each thread has its own histogram of `k` counters,
+//! where `k` is the range of the values, so the sort needs `threads * k`
+//! counters instead of `k`. The range is therefore limited by [`MAX_RANGE`]
+//! as well, and the number of threads by the parallelism the machine offers,
+//! since more threads than cores only cost memory. With 8 byte counters, each
+//! thread needs up to 128 MiB.
+//!
+//! Spawning threads isn't free either. Each thread gets a chunk of at least
+//! [`PARALLEL_THRESHOLD`] elements, so shorter arrays are sorted on the
+//! current thread.
+//!
+//! [Counting sort]: ../counting_sort/index.html
+//! [`MAX_RANGE`]: ../counting_sort/constant.MAX_RANGE.html
+//! [`PARALLEL_THRESHOLD`]: constant.PARALLEL_THRESHOLD.html
+
+use super::counting_sort::{counting_sort, value_range, write_counts, CountingSortError};
+use std::thread;
+
+/// The fewest elements a thread counts. Arrays shorter than twice this are
+/// sorted by the sequential [`counting_sort`].
+///
+/// [`counting_sort`]: ../counting_sort/fn.counting_sort.html
+pub const PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// Sorts the slice of integers in ASC order, counting chunks of the slice on
+/// up to given number of threads. Like [`counting_sort`], it leaves the slice
+/// untouched if the values span more than [`MAX_RANGE`] distinct values.
+///
+/// The number of threads is capped by the available parallelism and by the
+/// length of the array, see the [module] documentation. Zero threads means
+/// sorting on the current thread.
+///
+/// [`counting_sort`]: ../counting_sort/fn.counting_sort.html
+/// [`MAX_RANGE`]: ../counting_sort/constant.MAX_RANGE.html
+/// [module]: index.html
+pub fn par_counting_sort(array: &mut [u32], threads: usize) -> Result<(), CountingSortError> {
+    let available = thread::available_parallelism().map_or(1, |n| n.get());
+    let threads = threads
+        .min(available)
+        .min(array.len() / PARALLEL_THRESHOLD)
+        .max(1);
+
+    if threads == 1 {
+        counting_sort(array)
+    } else {
+        sort_in_chunks(array, threads)
+    }
+}
+
+/// Counts the array split into given number of chunks, each on its own
+/// thread, and writes the sorted values back.
+fn sort_in_chunks(array: &mut [u32], threads: usize) -> Result<(), CountingSortError> {
+    let (min, range) = match value_range(array)? {
+        Some(bounds) => bounds,
+        None => return Ok(()),
+    };
+
+    let chunk_len = array.len().div_ceil(threads);
+    let histograms: Vec<Vec<usize>> = thread::scope(|scope| {
+        let workers: Vec<_> = array
+            .chunks(chunk_len)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    let mut counts = vec![0usize; range];
+                    for &value in chunk {
+                        counts[(value - min) as usize] += 1;
+                    }
+
+                    counts
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("counting thread panicked"))
+            .collect()
+    });
+
+    let mut counts = vec![0usize; range];
+    for histogram in histograms {
+        for (total, count) in counts.iter_mut().zip(histogram) {
+            *total += count;
+        }
+    }
+
+    write_counts(array, min, &counts);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let mut array: Vec<u32> = Vec::new();
+
+        assert_eq!(par_counting_sort(&mut array, 4), Ok(()));
+    }
+
+    #[test]
+    fn it_sorts_short_array_sequentially() {
+        let mut array = vec![3, 1, 3, 0, 1, 3];
+
+        assert_eq!(par_counting_sort(&mut array, 4), Ok(()));
+        assert_eq!(array, vec![0, 1, 1, 3, 3, 3]);
+    }
+
+    #[test]
+    fn zero_threads_sort_on_current_thread() {
+        let mut array: Vec<u32> = (0..PARALLEL_THRESHOLD as u32 * 2).rev().collect();
+
+        assert_eq!(par_counting_sort(&mut array, 0), Ok(()));
+
+        assert!(is_sorted(&array));
+    }
+
+    #[test]
+    fn it_refuses_too_large_range() {
+        let mut array = vec![0; PARALLEL_THRESHOLD];
+        array[7] = u32::MAX;
+        let original = array.clone();
+
+        let error = sort_in_chunks(&mut array, 4).unwrap_err();
+
+        assert_eq!(error.range, 1 << 32);
+        assert_eq!(array, original);
+    }
+
+    #[test]
+    fn it_matches_sequential_sort() {
+        for threads in &[2, 3, 8] {
+            let mut rng = SeededRng::new(*threads as u64);
+            let mut array: Vec<u32> = (0..PARALLEL_THRESHOLD * 4 + 7)
+                .map(|_| 1_000 + rng.below(50_000) as u32)
+                .collect();
+            let mut expected = array.clone();
+
+            counting_sort(&mut expected).unwrap();
+            // Calls the parallel sort directly, because the public function
+            // caps threads by the parallelism of the machine running tests.
+            assert_eq!(sort_in_chunks(&mut array, *threads), Ok(()));
+
+            assert_eq!(array, expected);
+        }
+    }
+
+    #[test]
+    fn it_sorts_reversed_array_on_many_threads() {
+        let mut array: Vec<u32> = (0..PARALLEL_THRESHOLD as u32).rev().collect();
+
+        assert_eq!(par_counting_sort(&mut array, 100), Ok(()));
+
+        assert_eq!(array, (0..PARALLEL_THRESHOLD as u32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_matches_sequential_sort_with_capped_threads() {
+        let mut rng = SeededRng::new(7);
+        let mut array: Vec<u32> = (0..PARALLEL_THRESHOLD * 3)
+            .map(|_| rng.below(1_000) as u32)
+            .collect();
+        let mut expected = array.clone();
+
+        counting_sort(&mut expected).unwrap();
+        assert_eq!(par_counting_sort(&mut array, usize::MAX), Ok(()));
+
+        assert_eq!(array, expected);
+    }
+}
@@ -0,0 +1,196 @@
+//! # Sorting network
+//!
+//! A sorting network is a fixed sequence of _comparators_. A comparator takes
+//! two positions of the array and exchanges their elements if they are out of
+//! order. Which positions get compared never depends on the data, unlike in
+//! any of the sorts which decide where to look next based on the previous
+//! comparison. That makes networks easy to run in parallel or without
+//! branching, and fast sorts use them for their smallest subarrays.
+//!
+//! ```text
+//!     0 ---*---------*-----------
+//!          |         |
+//!     1 ---*----*----|----*------
+//!               |    |    |
+//!     2 ---*----|----*----*------
+//!          |    |
+//!     3 ---*----*----------------
+//! ```
+//!
+//! Correctness of a network can be checked thanks to the _0-1 principle_: if
+//! it sorts all sequences of zeros and ones, it sorts all sequences. The
+//! networks below are the ones with the fewest comparators known for each
+//! length.
+
+use super::a_002_straight_insertion::straight_insertion;
+
+/// Pairs of positions compared by the network of given length.
+const NETWORKS: [&[(usize, usize)]; 9] = [
+    &[],
+    &[],
+    &[(0, 1)],
+    &[(0, 2), (0, 1), (1, 2)],
+    &[(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)],
+    &[
+        (0, 1),
+        (3, 4),
+        (2, 4),
+        (2, 3),
+        (0, 3),
+        (0, 2),
+        (1, 4),
+        (1, 3),
+        (1, 2),
+    ],
+    &[
+        (1, 2),
+        (4, 5),
+        (0, 2),
+        (3, 5),
+        (0, 1),
+        (3, 4),
+        (1, 4),
+        (0, 3),
+        (2, 5),
+        (1, 3),
+        (2, 4),
+        (2, 3),
+    ],
+    &[
+        (1, 2),
+        (3, 4),
+        (5, 6),
+        (0, 2),
+        (3, 5),
+        (4, 6),
+        (0, 1),
+        (4, 5),
+        (2, 6),
+        (0, 4),
+        (1, 5),
+        (0, 3),
+        (2, 5),
+        (1, 3),
+        (2, 4),
+        (2, 3),
+    ],
+    &[
+        (0, 2),
+        (1, 3),
+        (4, 6),
+        (5, 7),
+        (0, 4),
+        (1, 5),
+        (2, 6),
+        (3, 7),
+        (0, 1),
+        (2, 3),
+        (4, 5),
+        (6, 7),
+        (2, 4),
+        (3, 5),
+        (1, 4),
+        (3, 6),
+        (1, 2),
+        (3, 4),
+        (5, 6),
+    ],
+];
+
+/// Sorts arrays of up to 8 elements with a sorting network, and longer arrays
+/// with [`straight_insertion`]. The network sort is not stable.
+///
+/// [`straight_insertion`]: ../a_002_straight_insertion/fn.straight_insertion.html
+pub fn sort_network<T>(array: &mut [T])
+where
+    T: PartialOrd,
+{
+    let network = match NETWORKS.get(array.len()) {
+        Some(network) => network,
+        None => return straight_insertion(array),
+    };
+
+    for &(a, b) in network.iter() {
+        if array[b] < array[a] {
+            array.swap(a, b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    /// Calls given function with every permutation of `0..len`, generated by
+    /// Heap's algorithm.
+    fn for_each_permutation<F>(len: usize, mut f: F)
+    where
+        F: FnMut(&[u8]),
+    {
+        let mut permutation: Vec<u8> = (0..len as u8).collect();
+        let mut counters = vec![0; len];
+        f(&permutation);
+
+        let mut index = 1;
+        while index < len {
+            if counters[index] < index {
+                let other = if index % 2 == 0 { 0 } else { counters[index] };
+                permutation.swap(other, index);
+                f(&permutation);
+                counters[index] += 1;
+                index = 1;
+            } else {
+                counters[index] = 0;
+                index += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn it_sorts_all_permutations_of_short_arrays() {
+        for len in 0..=5 {
+            let mut count = 0;
+
+            for_each_permutation(len, |permutation| {
+                let mut array = permutation.to_vec();
+                sort_network(&mut array);
+                assert_eq!(array, (0..len as u8).collect::<Vec<_>>());
+                count += 1;
+            });
+
+            assert_eq!(count, (1..=len).product::<usize>());
+        }
+    }
+
+    #[test]
+    fn it_sorts_all_zero_one_sequences() {
+        for len in 0..NETWORKS.len() {
+            for bits in 0..1u32 << len {
+                let mut array: Vec<u32> = (0..len).map(|bit| (bits >> bit) & 1).collect();
+
+                sort_network(&mut array);
+
+                assert!(is_sorted(&array), "{:?} of length {}", array, len);
+            }
+        }
+    }
+
+    #[test]
+    fn it_sorts_example() {
+        let array = [44, 55, 12, 42, 94, 18, 6, 67];
+
+        assert_sorted_permutation(&array, sort_network);
+    }
+
+    #[test]
+    fn it_falls_back_on_longer_arrays() {
+        let mut numbers: Vec<u32> = (0..50).collect();
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            shuffle(&mut numbers, seed);
+            let len = 9 + seed as usize % 41;
+
+            assert_sorted_permutation(&numbers[..len], sort_network);
+        }
+    }
+}
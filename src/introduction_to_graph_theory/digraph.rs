@@ -0,0 +1,229 @@
+//! # Directed graph
+//!
+//! A directed graph, or _digraph_, is like a [`Graph`], except that its edges
+//! have a direction. Such edges are called _arcs_. An arc goes from its tail
+//! to its head. The number of arcs leaving a vertex is its _out-degree_ and
+//! the number of arcs entering it is its _in-degree_.
+//!
+//! Each vertex keeps two adjacency lists: one of its successors and one of
+//! its predecessors. That makes both kinds of queries cheap.
+//!
+//! [`Graph`]: ../graph/struct.Graph.html
+
+use std::collections::VecDeque;
+
+/// Directed graph represented by adjacency lists. At most one arc goes from
+/// one vertex to another. Loops are allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiGraph {
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl DiGraph {
+    /// Creates a digraph with given number of vertices and no arcs.
+    pub fn new(vertex_count: usize) -> Self {
+        DiGraph {
+            successors: vec![Vec::new(); vertex_count],
+            predecessors: vec![Vec::new(); vertex_count],
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.successors.len()
+    }
+
+    pub fn arc_count(&self) -> usize {
+        self.successors.iter().map(Vec::len).sum()
+    }
+
+    /// Adds an arc going from one vertex to another. Arcs which are already
+    /// present are ignored.
+    ///
+    /// Panics if either vertex is not in the graph.
+    pub fn add_arc(&mut self, from: usize, to: usize) {
+        assert!(from < self.vertex_count() && to < self.vertex_count());
+
+        if self.has_arc(from, to) {
+            return;
+        }
+
+        self.successors[from].push(to);
+        self.predecessors[to].push(from);
+    }
+
+    pub fn has_arc(&self, from: usize, to: usize) -> bool {
+        self.successors[from].contains(&to)
+    }
+
+    /// Vertices which the arcs leaving the vertex go to.
+    pub fn successors(&self, vertex: usize) -> &[usize] {
+        &self.successors[vertex]
+    }
+
+    /// Vertices which the arcs entering the vertex come from.
+    pub fn predecessors(&self, vertex: usize) -> &[usize] {
+        &self.predecessors[vertex]
+    }
+
+    pub fn out_degree(&self, vertex: usize) -> usize {
+        self.successors[vertex].len()
+    }
+
+    pub fn in_degree(&self, vertex: usize) -> usize {
+        self.predecessors[vertex].len()
+    }
+
+    /// Orders the vertices so that every arc goes from an earlier vertex to a
+    /// later one. Such an order exists if and only if the digraph has no
+    /// directed cycle. Returns `None` if it has one.
+    ///
+    /// Implements Kahn's algorithm. A vertex with in-degree zero can go first,
+    /// since no arc enters it. After it is removed together with its arcs, the
+    /// in-degrees of its successors drop and another vertex with in-degree zero
+    /// can go next. If the vertices run out before all of them have been
+    /// ordered, the remaining ones lie on a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        // Instead of removing arcs, remaining in-degrees are tracked.
+        let mut in_degrees: Vec<usize> = (0..self.vertex_count())
+            .map(|vertex| self.in_degree(vertex))
+            .collect();
+        let mut ready: VecDeque<usize> = (0..self.vertex_count())
+            .filter(|&vertex| in_degrees[vertex] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.vertex_count());
+
+        while let Some(vertex) = ready.pop_front() {
+            order.push(vertex);
+
+            for &successor in &self.successors[vertex] {
+                in_degrees[successor] -= 1;
+
+                if in_degrees[successor] == 0 {
+                    ready.push_back(successor);
+                }
+            }
+        }
+
+        if order.len() == self.vertex_count() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_tracks_degrees() {
+        let mut g = DiGraph::new(3);
+        g.add_arc(0, 1);
+        g.add_arc(0, 2);
+        g.add_arc(1, 2);
+
+        assert_eq!(g.arc_count(), 3);
+        assert_eq!(g.out_degree(0), 2);
+        assert_eq!(g.in_degree(0), 0);
+        assert_eq!(g.out_degree(1), 1);
+        assert_eq!(g.in_degree(1), 1);
+        assert_eq!(g.out_degree(2), 0);
+        assert_eq!(g.in_degree(2), 2);
+    }
+
+    #[test]
+    fn it_lists_successors_and_predecessors() {
+        let mut g = DiGraph::new(3);
+        g.add_arc(0, 2);
+        g.add_arc(1, 2);
+        g.add_arc(2, 0);
+
+        assert_eq!(g.successors(2), &[0]);
+        assert_eq!(g.predecessors(2), &[0, 1]);
+        assert!(g.has_arc(2, 0));
+        assert!(!g.has_arc(2, 1));
+    }
+
+    #[test]
+    fn it_ignores_duplicate_arcs() {
+        let mut g = DiGraph::new(2);
+        g.add_arc(0, 1);
+        g.add_arc(0, 1);
+
+        assert_eq!(g.arc_count(), 1);
+        assert_eq!(g.in_degree(1), 1);
+    }
+
+    #[test]
+    fn it_counts_loop_in_both_degrees() {
+        let mut g = DiGraph::new(1);
+        g.add_arc(0, 0);
+
+        assert_eq!(g.out_degree(0), 1);
+        assert_eq!(g.in_degree(0), 1);
+    }
+
+    fn is_topological_order(g: &DiGraph, order: &[usize]) -> bool {
+        let mut position = vec![0; g.vertex_count()];
+        for (index, &vertex) in order.iter().enumerate() {
+            position[vertex] = index;
+        }
+
+        order.len() == g.vertex_count()
+            && (0..g.vertex_count()).all(|from| {
+                g.successors(from)
+                    .iter()
+                    .all(|&to| position[from] < position[to])
+            })
+    }
+
+    #[test]
+    fn topological_sort_of_chain() {
+        let mut g = DiGraph::new(4);
+        g.add_arc(2, 0);
+        g.add_arc(0, 3);
+        g.add_arc(3, 1);
+
+        assert_eq!(g.topological_sort(), Some(vec![2, 0, 3, 1]));
+    }
+
+    #[test]
+    fn topological_sort_of_diamond() {
+        //    1
+        //  /  \
+        // 0    3
+        //  \  /
+        //    2
+        let mut g = DiGraph::new(4);
+        g.add_arc(0, 1);
+        g.add_arc(0, 2);
+        g.add_arc(1, 3);
+        g.add_arc(2, 3);
+
+        let order = g.topological_sort().unwrap();
+
+        assert!(is_topological_order(&g, &order));
+    }
+
+    #[test]
+    fn topological_sort_of_cycle_is_none() {
+        let mut g = DiGraph::new(4);
+        g.add_arc(0, 1);
+        g.add_arc(1, 2);
+        g.add_arc(2, 3);
+        g.add_arc(3, 1);
+
+        assert_eq!(g.topological_sort(), None);
+    }
+
+    #[test]
+    fn topological_sort_of_loop_is_none() {
+        let mut g = DiGraph::new(2);
+        g.add_arc(0, 1);
+        g.add_arc(1, 1);
+
+        assert_eq!(g.topological_sort(), None);
+    }
+}
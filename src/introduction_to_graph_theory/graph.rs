@@ -0,0 +1,1269 @@
+//! # Graph
+//!
+//! A graph is a set of vertices together with a set of edges, each of which
+//! joins two distinct vertices. The graphs in this module are simple: there
+//! are no loops (an edge joining a vertex with itself) and at most one edge
+//! joins any two vertices.
+//!
+//! Vertices are labeled with numbers `0..vertex_count`. Each vertex keeps a
+//! list of its neighbours, which is called an _adjacency list_. Neighbours are
+//! listed in the order in which the edges were added, which makes traversals
+//! deterministic.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::convert::TryFrom;
+
+/// Undirected simple graph represented by adjacency lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// Creates a graph with given number of vertices and no edges.
+    pub fn new(vertex_count: usize) -> Self {
+        Graph {
+            adjacency: vec![Vec::new(); vertex_count],
+        }
+    }
+
+    /// Creates the wheel W_n: a cycle on vertices `0..n` and a hub, vertex
+    /// `n`, joined with each of them.
+    ///
+    /// Panics if `n < 3`, as there is no cycle on fewer vertices.
+    pub fn wheel(n: usize) -> Self {
+        assert!(n >= 3, "wheel needs a cycle of at least three vertices");
+
+        let mut g = Graph::new(n + 1);
+        for vertex in 0..n {
+            g.add_edge(vertex, (vertex + 1) % n);
+            g.add_edge(vertex, n);
+        }
+
+        g
+    }
+
+    /// Creates the complete bipartite graph K_{m,n}: each of the vertices
+    /// `0..m` is joined with each of the vertices `m..m + n`, and there are
+    /// no other edges.
+    pub fn complete_bipartite(m: usize, n: usize) -> Self {
+        let mut g = Graph::new(m + n);
+        for a in 0..m {
+            for b in m..m + n {
+                g.add_edge(a, b);
+            }
+        }
+
+        g
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        // Each edge is listed by both of its ends.
+        self.adjacency.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// Joins two vertices with an edge. Loops and edges which are already
+    /// present are ignored so that the graph stays simple.
+    ///
+    /// Panics if either vertex is not in the graph.
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        assert!(a < self.vertex_count() && b < self.vertex_count());
+
+        if a == b || self.has_edge(a, b) {
+            return;
+        }
+
+        self.adjacency[a].push(b);
+        self.adjacency[b].push(a);
+    }
+
+    pub fn has_edge(&self, a: usize, b: usize) -> bool {
+        self.adjacency[a].contains(&b)
+    }
+
+    pub fn neighbours(&self, vertex: usize) -> &[usize] {
+        &self.adjacency[vertex]
+    }
+
+    /// Number of edges incident with the vertex.
+    pub fn degree(&self, vertex: usize) -> usize {
+        self.adjacency[vertex].len()
+    }
+
+    /// Returns vertices reachable from the start in the order in which depth
+    /// first search discovers them. Neighbours are explored in the order of
+    /// the adjacency list.
+    ///
+    /// An explicit stack is used instead of recursion. A vertex can be pushed
+    /// onto the stack multiple times, but it's visited only the first time it
+    /// is popped.
+    pub fn dfs_order(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.vertex_count()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(vertex) = stack.pop() {
+            if visited[vertex] {
+                continue;
+            }
+
+            visited[vertex] = true;
+            order.push(vertex);
+
+            // Pushes neighbours in reverse so that the first neighbour is
+            // popped first.
+            for &neighbour in self.adjacency[vertex].iter().rev() {
+                if !visited[neighbour] {
+                    stack.push(neighbour);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Returns every path from one vertex to another which visits no vertex
+    /// twice. Each path lists its vertices, starting with `from` and ending
+    /// with `to`. The number of such paths grows exponentially with the size
+    /// of the graph, so paths can be limited to at most `max_len` edges.
+    ///
+    /// Paths are found by depth first search which backtracks: once all
+    /// continuations of a path have been explored, its last vertex is removed
+    /// and marked as unvisited again, so that other paths can go through it.
+    pub fn all_simple_paths(
+        &self,
+        from: usize,
+        to: usize,
+        max_len: Option<usize>,
+    ) -> Vec<Vec<usize>> {
+        let mut paths = Vec::new();
+        let mut visited = vec![false; self.vertex_count()];
+        let mut path = vec![from];
+        visited[from] = true;
+
+        self.extend_simple_paths(to, max_len, &mut path, &mut visited, &mut paths);
+
+        paths
+    }
+
+    fn extend_simple_paths(
+        &self,
+        to: usize,
+        max_len: Option<usize>,
+        path: &mut Vec<usize>,
+        visited: &mut [bool],
+        paths: &mut Vec<Vec<usize>>,
+    ) {
+        let last = path[path.len() - 1];
+        if last == to {
+            paths.push(path.clone());
+            return;
+        }
+
+        // The path has as many edges as it has vertices minus one.
+        if max_len.is_some_and(|max_len| path.len() > max_len) {
+            return;
+        }
+
+        for &neighbour in &self.adjacency[last] {
+            if visited[neighbour] {
+                continue;
+            }
+
+            visited[neighbour] = true;
+            path.push(neighbour);
+            self.extend_simple_paths(to, max_len, path, visited, paths);
+            path.pop();
+            visited[neighbour] = false;
+        }
+    }
+
+    /// Returns the length of the shortest cycle in the graph, which is called
+    /// its _girth_, or `None` if the graph has no cycle.
+    ///
+    /// Breadth first search is run from each vertex. When it reaches an
+    /// already discovered vertex by an edge which is not in the search tree,
+    /// the edge closes a cycle through both tree branches. The search from a
+    /// vertex which lies on a shortest cycle finds that cycle exactly, other
+    /// searches can only find longer ones. This runs in time O(V * (V + E)).
+    pub fn girth(&self) -> Option<usize> {
+        let mut girth: Option<usize> = None;
+
+        for start in 0..self.vertex_count() {
+            let mut distance: Vec<Option<usize>> = vec![None; self.vertex_count()];
+            let mut parent = vec![usize::MAX; self.vertex_count()];
+            let mut queue = VecDeque::new();
+            distance[start] = Some(0);
+            queue.push_back(start);
+
+            while let Some(vertex) = queue.pop_front() {
+                let vertex_distance = distance[vertex].unwrap_or_default();
+
+                for &neighbour in &self.adjacency[vertex] {
+                    match distance[neighbour] {
+                        None => {
+                            distance[neighbour] = Some(vertex_distance + 1);
+                            parent[neighbour] = vertex;
+                            queue.push_back(neighbour);
+                        }
+                        Some(neighbour_distance) if parent[vertex] != neighbour => {
+                            let cycle = vertex_distance + neighbour_distance + 1;
+                            girth = Some(girth.map_or(cycle, |girth| girth.min(cycle)));
+                        }
+                        Some(_) => (),
+                    }
+                }
+            }
+        }
+
+        girth
+    }
+
+    /// Whether there is a closed walk which traverses every edge exactly once.
+    ///
+    /// Euler's answer to the problem of the bridges of Königsberg: such a walk
+    /// exists if and only if all edges are in one connected component and
+    /// every vertex has even degree. A walk enters a vertex as many times as
+    /// it leaves it, hence it uses an even number of its edges.
+    pub fn has_eulerian_circuit(&self) -> bool {
+        self.edges_are_connected() && self.odd_degree_count() == 0
+    }
+
+    /// Whether there is a walk which traverses every edge exactly once. Unlike
+    /// a circuit, the walk can start and end on different vertices, which are
+    /// then the only two of odd degree.
+    pub fn has_eulerian_path(&self) -> bool {
+        self.edges_are_connected() && matches!(self.odd_degree_count(), 0 | 2)
+    }
+
+    fn odd_degree_count(&self) -> usize {
+        (0..self.vertex_count())
+            .filter(|&vertex| self.degree(vertex) % 2 == 1)
+            .count()
+    }
+
+    /// Whether all vertices which have any edge are in the same component.
+    /// Isolated vertices don't matter for walks along edges.
+    fn edges_are_connected(&self) -> bool {
+        let start = match (0..self.vertex_count()).find(|&vertex| self.degree(vertex) > 0) {
+            Some(start) => start,
+            None => return true,
+        };
+
+        let reached = self.dfs_order(start).len();
+        let non_isolated = (0..self.vertex_count())
+            .filter(|&vertex| self.degree(vertex) > 0)
+            .count();
+
+        reached == non_isolated
+    }
+
+    /// Whether the graph is a tree: it's connected and has no cycle. A
+    /// connected graph on V vertices has no cycle if and only if it has
+    /// exactly V - 1 edges.
+    pub fn is_tree(&self) -> bool {
+        self.vertex_count() > 0
+            && self.edge_count() == self.vertex_count() - 1
+            && self.dfs_order(0).len() == self.vertex_count()
+    }
+
+    /// Encodes a tree on `n >= 2` vertices as its Prüfer sequence of length
+    /// `n - 2`, or returns `None` if the graph is not such a tree.
+    ///
+    /// The leaf with the smallest label is removed and the label of its
+    /// neighbour is appended to the sequence, until two vertices remain. Each
+    /// vertex then appears in the sequence one time less than its degree.
+    /// Labeled trees and sequences correspond one to one, which proves
+    /// Cayley's formula: there are `n^(n - 2)` labeled trees on `n` vertices.
+    pub fn prufer_encode(&self) -> Option<Vec<usize>> {
+        let n = self.vertex_count();
+        if n < 2 || !self.is_tree() {
+            return None;
+        }
+
+        let mut degree: Vec<usize> = (0..n).map(|vertex| self.degree(vertex)).collect();
+        let mut removed = vec![false; n];
+        let mut leaves: BinaryHeap<_> = (0..n)
+            .filter(|&vertex| degree[vertex] == 1)
+            .map(Reverse)
+            .collect();
+
+        let mut sequence = Vec::with_capacity(n - 2);
+        while sequence.len() < n - 2 {
+            let Reverse(leaf) = leaves.pop()?;
+            removed[leaf] = true;
+
+            let neighbour = *self.adjacency[leaf]
+                .iter()
+                .find(|&&neighbour| !removed[neighbour])?;
+            sequence.push(neighbour);
+
+            degree[neighbour] -= 1;
+            if degree[neighbour] == 1 {
+                leaves.push(Reverse(neighbour));
+            }
+        }
+
+        Some(sequence)
+    }
+
+    /// Counts the spanning trees of the graph: subgraphs which contain all of
+    /// its vertices and are trees. A disconnected graph has none.
+    ///
+    /// By Kirchhoff's matrix-tree theorem, the count equals the determinant of
+    /// the Laplacian matrix with any one row and the same column deleted. The
+    /// Laplacian has vertex degrees on its diagonal and -1 wherever two
+    /// vertices are adjacent. The determinant is computed by Bareiss'
+    /// fraction-free elimination. It divides only by the previous pivot, which
+    /// always divides exactly, so all intermediate values stay integers.
+    ///
+    /// Panics if the count overflows `u64`.
+    pub fn spanning_tree_count(&self) -> u64 {
+        let n = self.vertex_count();
+        if n == 0 {
+            return 0;
+        }
+
+        // Laplacian without the last row and column.
+        let size = n - 1;
+        let mut matrix = vec![vec![0i128; size]; size];
+        for (a, row) in matrix.iter_mut().enumerate() {
+            row[a] = self.degree(a) as i128;
+            for &b in &self.adjacency[a] {
+                if b < size {
+                    row[b] = -1;
+                }
+            }
+        }
+
+        let mut sign = 1;
+        let mut previous_pivot = 1;
+        for k in 0..size {
+            // Swapping two rows flips the sign of the determinant.
+            if matrix[k][k] == 0 {
+                match (k + 1..size).find(|&row| matrix[row][k] != 0) {
+                    Some(row) => {
+                        matrix.swap(k, row);
+                        sign = -sign;
+                    }
+                    None => return 0,
+                }
+            }
+
+            for i in k + 1..size {
+                for j in k + 1..size {
+                    matrix[i][j] = (matrix[i][j] * matrix[k][k] - matrix[i][k] * matrix[k][j])
+                        / previous_pivot;
+                }
+            }
+            previous_pivot = matrix[k][k];
+        }
+
+        // After the elimination the last pivot is the determinant.
+        let determinant = sign * previous_pivot;
+        u64::try_from(determinant).expect("spanning tree count overflows u64")
+    }
+
+    /// Whether the two graphs are isomorphic: there is a relabeling of the
+    /// vertices of one graph which makes it equal to the other one.
+    ///
+    /// Graphs with different degree sequences are rejected right away.
+    /// Otherwise the vertices of this graph are mapped one by one onto
+    /// unused vertices of the other graph of the same degree. A mapping is
+    /// kept only if it preserves adjacency with all vertices mapped so far,
+    /// else the search backtracks. The worst case still tries all V!
+    /// permutations, so this is meant for graphs of a dozen or so vertices.
+    pub fn is_isomorphic_to(&self, other: &Graph) -> bool {
+        if self.vertex_count() != other.vertex_count() || self.edge_count() != other.edge_count() {
+            return false;
+        }
+
+        let degree_sequence = |g: &Graph| {
+            let mut degrees: Vec<_> = (0..g.vertex_count()).map(|v| g.degree(v)).collect();
+            degrees.sort_unstable();
+            degrees
+        };
+        if degree_sequence(self) != degree_sequence(other) {
+            return false;
+        }
+
+        let mut mapping = Vec::with_capacity(self.vertex_count());
+        let mut used = vec![false; other.vertex_count()];
+        self.extend_isomorphism(other, &mut mapping, &mut used)
+    }
+
+    /// Tries to map the next vertex of this graph, whose index is the length
+    /// of the mapping so far.
+    fn extend_isomorphism(
+        &self,
+        other: &Graph,
+        mapping: &mut Vec<usize>,
+        used: &mut [bool],
+    ) -> bool {
+        let vertex = mapping.len();
+        if vertex == self.vertex_count() {
+            return true;
+        }
+
+        for candidate in 0..other.vertex_count() {
+            if used[candidate] || self.degree(vertex) != other.degree(candidate) {
+                continue;
+            }
+
+            let preserves_adjacency = mapping.iter().enumerate().all(|(mapped, &image)| {
+                self.has_edge(vertex, mapped) == other.has_edge(candidate, image)
+            });
+            if !preserves_adjacency {
+                continue;
+            }
+
+            mapping.push(candidate);
+            used[candidate] = true;
+            if self.extend_isomorphism(other, mapping, used) {
+                return true;
+            }
+            used[candidate] = false;
+            mapping.pop();
+        }
+
+        false
+    }
+
+    /// Whether the vertices can be split into two sets such that every edge
+    /// joins a vertex of one set with a vertex of the other. That's the case
+    /// if and only if the graph has no cycle of odd length.
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+
+    /// Splits the vertices into two sides such that every edge joins the two
+    /// sides, or returns `None` if the graph is not bipartite. A vertex is on
+    /// the side `true` or `false`.
+    ///
+    /// Each component is coloured by breadth first search: a vertex gets the
+    /// colour opposite to the vertex it was discovered from. The graph is
+    /// bipartite unless some edge joins two vertices of the same colour.
+    fn bipartition(&self) -> Option<Vec<bool>> {
+        let mut colour: Vec<Option<bool>> = vec![None; self.vertex_count()];
+
+        for start in 0..self.vertex_count() {
+            if colour[start].is_some() {
+                continue;
+            }
+
+            colour[start] = Some(false);
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+
+            while let Some(vertex) = queue.pop_front() {
+                let vertex_colour = colour[vertex];
+                for &neighbour in &self.adjacency[vertex] {
+                    match colour[neighbour] {
+                        None => {
+                            colour[neighbour] = vertex_colour.map(|c| !c);
+                            queue.push_back(neighbour);
+                        }
+                        Some(neighbour_colour) if Some(neighbour_colour) == vertex_colour => {
+                            return None;
+                        }
+                        Some(_) => (),
+                    }
+                }
+            }
+        }
+
+        colour.into_iter().collect()
+    }
+
+    /// Returns a largest set of edges of a bipartite graph no two of which
+    /// share a vertex. Each edge is returned as a pair whose first vertex is
+    /// on the same side as vertex 0, and the pairs are sorted.
+    ///
+    /// Vertices of one side are matched one by one. For each of them, depth
+    /// first search looks for an _augmenting path_: a path which alternates
+    /// between edges outside and inside of the matching and ends on a vertex
+    /// which is not matched yet. Flipping the edges along such a path grows
+    /// the matching by one. By Berge's theorem, a matching is maximum when no
+    /// augmenting path is left. This runs in time O(V * E).
+    ///
+    /// Panics if the graph is not bipartite.
+    pub fn maximum_bipartite_matching(&self) -> Vec<(usize, usize)> {
+        let side = self.bipartition().expect("graph is not bipartite");
+
+        // For each vertex of the right side, its partner on the left side.
+        let mut partner: Vec<Option<usize>> = vec![None; self.vertex_count()];
+        for vertex in (0..self.vertex_count()).filter(|&vertex| !side[vertex]) {
+            let mut visited = vec![false; self.vertex_count()];
+            self.augment(vertex, &mut partner, &mut visited);
+        }
+
+        let mut matching: Vec<_> = partner
+            .iter()
+            .enumerate()
+            .filter_map(|(right, left)| left.map(|left| (left, right)))
+            .collect();
+        matching.sort_unstable();
+
+        matching
+    }
+
+    /// Looks for an augmenting path from the left vertex and flips it if it
+    /// exists. A right vertex is visited at most once per search.
+    fn augment(&self, left: usize, partner: &mut [Option<usize>], visited: &mut [bool]) -> bool {
+        for &right in &self.adjacency[left] {
+            if visited[right] {
+                continue;
+            }
+            visited[right] = true;
+
+            // Either the right vertex is free, or its partner can be
+            // rematched elsewhere.
+            let is_free = match partner[right] {
+                None => true,
+                Some(other) => self.augment(other, partner, visited),
+            };
+            if is_free {
+                partner[right] = Some(left);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Returns edges whose removal increases the number of components, which
+    /// are called _bridges_. Each edge is returned as a pair of its ends with
+    /// the smaller one first, and the pairs are sorted.
+    ///
+    /// Depth first search numbers vertices in the order of their discovery.
+    /// The _low link_ of a vertex is the smallest number reachable from its
+    /// subtree by a single edge which is not in the search tree. A tree edge
+    /// from a parent to its child is a bridge if the child's low link is larger
+    /// than the parent's number: nothing below the edge reaches back above it,
+    /// so no cycle goes through the edge.
+    pub fn bridges(&self) -> Vec<(usize, usize)> {
+        let mut discovered: Vec<Option<usize>> = vec![None; self.vertex_count()];
+        let mut low = vec![0; self.vertex_count()];
+        let mut bridges = Vec::new();
+        let mut time = 0;
+
+        for root in 0..self.vertex_count() {
+            if discovered[root].is_some() {
+                continue;
+            }
+
+            // An explicit stack of vertices, their parents and how many of
+            // their neighbours have been explored replaces recursion.
+            discovered[root] = Some(time);
+            low[root] = time;
+            time += 1;
+            let mut stack = vec![(root, None, 0)];
+
+            while let Some((vertex, parent, explored)) = stack.pop() {
+                if let Some(&neighbour) = self.adjacency[vertex].get(explored) {
+                    stack.push((vertex, parent, explored + 1));
+
+                    match discovered[neighbour] {
+                        None => {
+                            discovered[neighbour] = Some(time);
+                            low[neighbour] = time;
+                            time += 1;
+                            stack.push((neighbour, Some(vertex), 0));
+                        }
+                        Some(number) if parent != Some(neighbour) => {
+                            low[vertex] = low[vertex].min(number);
+                        }
+                        Some(_) => (),
+                    }
+                } else if let Some(parent) = parent {
+                    // The vertex is finished, its low link is final.
+                    low[parent] = low[parent].min(low[vertex]);
+                    if discovered[parent].is_some_and(|number| low[vertex] > number) {
+                        bridges.push((parent.min(vertex), parent.max(vertex)));
+                    }
+                }
+            }
+        }
+
+        bridges.sort_unstable();
+        bridges
+    }
+
+    /// Returns the centre of a tree: the vertices whose greatest distance to
+    /// any other vertex is the smallest. A tree has one or two of them, which
+    /// are then adjacent. Returns an empty vector if the graph is not a tree.
+    pub fn tree_center(&self) -> Vec<usize> {
+        self.peel_leaves()
+            .map(|(center, _)| center)
+            .unwrap_or_default()
+    }
+
+    /// Greatest distance from the centre of a tree to any vertex, or `None`
+    /// if the graph is not a tree.
+    pub fn radius(&self) -> Option<usize> {
+        let (center, rounds) = self.peel_leaves()?;
+
+        Some(rounds + center.len() - 1)
+    }
+
+    /// Greatest distance between any two vertices of a tree, or `None` if the
+    /// graph is not a tree.
+    pub fn diameter(&self) -> Option<usize> {
+        let (center, rounds) = self.peel_leaves()?;
+
+        Some(2 * rounds + center.len() - 1)
+    }
+
+    /// Removes all leaves of a tree at once, over and over, until one or two
+    /// vertices remain. Every longest path loses both of its ends in each
+    /// round, so its middle vertices, which form the centre, remain last.
+    /// Returns the remaining vertices and the number of rounds.
+    fn peel_leaves(&self) -> Option<(Vec<usize>, usize)> {
+        if !self.is_tree() {
+            return None;
+        }
+
+        let mut degree: Vec<usize> = (0..self.vertex_count()).map(|v| self.degree(v)).collect();
+        let mut layer: Vec<usize> = (0..self.vertex_count())
+            .filter(|&vertex| degree[vertex] <= 1)
+            .collect();
+        let mut remaining = self.vertex_count();
+        let mut rounds = 0;
+
+        while remaining > 2 {
+            remaining -= layer.len();
+            rounds += 1;
+
+            let mut next_layer = Vec::new();
+            for &leaf in &layer {
+                for &neighbour in &self.adjacency[leaf] {
+                    degree[neighbour] -= 1;
+                    if degree[neighbour] == 1 {
+                        next_layer.push(neighbour);
+                    }
+                }
+            }
+            layer = next_layer;
+        }
+
+        layer.sort_unstable();
+        Some((layer, rounds))
+    }
+}
+
+/// Decodes a Prüfer sequence into the tree on `n` vertices which it encodes.
+/// See [`Graph::prufer_encode`].
+///
+/// The degree of each vertex is one more than the number of its occurrences
+/// in the sequence. The leaf with the smallest label is joined with the first
+/// vertex of the sequence, which is then consumed. The last two vertices left
+/// are joined with each other.
+///
+/// Panics unless `n >= 2`, the sequence has length `n - 2` and all its
+/// elements are vertices.
+///
+/// [`Graph::prufer_encode`]: struct.Graph.html#method.prufer_encode
+pub fn prufer_decode(seq: &[usize], n: usize) -> Graph {
+    assert!(
+        n >= 2 && seq.len() == n - 2,
+        "sequence must have length n - 2"
+    );
+    assert!(seq.iter().all(|&vertex| vertex < n), "vertex out of bounds");
+
+    let mut degree = vec![1; n];
+    for &vertex in seq {
+        degree[vertex] += 1;
+    }
+    let mut leaves: BinaryHeap<_> = (0..n)
+        .filter(|&vertex| degree[vertex] == 1)
+        .map(Reverse)
+        .collect();
+
+    let mut tree = Graph::new(n);
+    for &vertex in seq {
+        if let Some(Reverse(leaf)) = leaves.pop() {
+            tree.add_edge(leaf, vertex);
+        }
+
+        degree[vertex] -= 1;
+        if degree[vertex] == 1 {
+            leaves.push(Reverse(vertex));
+        }
+    }
+
+    if let (Some(Reverse(a)), Some(Reverse(b))) = (leaves.pop(), leaves.pop()) {
+        tree.add_edge(a, b);
+    }
+
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///         0       6
+    ///        / \
+    ///       1   2
+    ///      / \   \
+    ///     3   4   5
+    fn tree() -> Graph {
+        let mut g = Graph::new(7);
+        g.add_edge(0, 1);
+        g.add_edge(0, 2);
+        g.add_edge(1, 3);
+        g.add_edge(1, 4);
+        g.add_edge(2, 5);
+
+        g
+    }
+
+    #[test]
+    fn it_counts_edges_and_degrees() {
+        let g = tree();
+
+        assert_eq!(g.vertex_count(), 7);
+        assert_eq!(g.edge_count(), 5);
+        assert_eq!(g.degree(1), 3);
+        assert_eq!(g.degree(6), 0);
+        assert!(g.has_edge(3, 1));
+        assert!(!g.has_edge(3, 4));
+    }
+
+    #[test]
+    fn it_stays_simple() {
+        let mut g = tree();
+
+        g.add_edge(0, 0);
+        g.add_edge(1, 0);
+
+        assert_eq!(g.edge_count(), 5);
+    }
+
+    #[test]
+    fn dfs_order_goes_deep_first() {
+        let g = tree();
+
+        assert_eq!(g.dfs_order(0), vec![0, 1, 3, 4, 2, 5]);
+        assert_eq!(g.dfs_order(2), vec![2, 0, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn dfs_order_omits_unreachable_vertices() {
+        let g = tree();
+
+        assert_eq!(g.dfs_order(6), vec![6]);
+        assert!(!g.dfs_order(0).contains(&6));
+    }
+
+    #[test]
+    fn dfs_order_handles_cycles() {
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 3);
+        g.add_edge(3, 0);
+
+        assert_eq!(g.dfs_order(0), vec![0, 1, 2, 3]);
+    }
+
+    ///     0 --- 1
+    ///     |   / |
+    ///     |  /  |
+    ///     2 --- 3 --- 4
+    fn diamond() -> Graph {
+        let mut g = Graph::new(5);
+        g.add_edge(0, 1);
+        g.add_edge(0, 2);
+        g.add_edge(1, 2);
+        g.add_edge(1, 3);
+        g.add_edge(2, 3);
+        g.add_edge(3, 4);
+
+        g
+    }
+
+    #[test]
+    fn it_finds_all_simple_paths() {
+        let g = diamond();
+
+        let mut paths = g.all_simple_paths(0, 4, None);
+        paths.sort();
+
+        assert_eq!(
+            paths,
+            vec![
+                vec![0, 1, 2, 3, 4],
+                vec![0, 1, 3, 4],
+                vec![0, 2, 1, 3, 4],
+                vec![0, 2, 3, 4],
+            ]
+        );
+    }
+
+    #[test]
+    fn it_limits_length_of_simple_paths() {
+        let g = diamond();
+
+        let mut paths = g.all_simple_paths(0, 4, Some(3));
+        paths.sort();
+
+        assert_eq!(paths, vec![vec![0, 1, 3, 4], vec![0, 2, 3, 4]]);
+        assert!(g.all_simple_paths(0, 4, Some(2)).is_empty());
+    }
+
+    #[test]
+    fn simple_paths_to_unreachable_or_same_vertex() {
+        let g = tree();
+
+        assert!(g.all_simple_paths(0, 6, None).is_empty());
+        assert_eq!(g.all_simple_paths(3, 3, Some(0)), vec![vec![3]]);
+        assert_eq!(g.all_simple_paths(3, 5, None), vec![vec![3, 1, 0, 2, 5]]);
+    }
+
+    fn cycle(len: usize) -> Graph {
+        let mut g = Graph::new(len);
+        for vertex in 0..len {
+            g.add_edge(vertex, (vertex + 1) % len);
+        }
+
+        g
+    }
+
+    #[test]
+    fn girth_of_cycles() {
+        assert_eq!(cycle(3).girth(), Some(3));
+        assert_eq!(cycle(4).girth(), Some(4));
+        assert_eq!(cycle(7).girth(), Some(7));
+    }
+
+    #[test]
+    fn girth_is_shortest_cycle() {
+        assert_eq!(diamond().girth(), Some(3));
+
+        let mut g = cycle(6);
+        g.add_edge(0, 3);
+        assert_eq!(g.girth(), Some(4));
+    }
+
+    #[test]
+    fn tree_has_no_girth() {
+        assert_eq!(tree().girth(), None);
+        assert_eq!(Graph::new(0).girth(), None);
+    }
+
+    #[test]
+    fn graph_with_even_degrees_has_eulerian_circuit() {
+        // Two triangles sharing a vertex, and an isolated vertex.
+        let mut g = Graph::new(6);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        g.add_edge(2, 3);
+        g.add_edge(3, 4);
+        g.add_edge(4, 2);
+
+        assert!(g.has_eulerian_circuit());
+        assert!(g.has_eulerian_path());
+    }
+
+    #[test]
+    fn graph_with_two_odd_degrees_has_only_eulerian_path() {
+        let mut g = cycle(4);
+        g.add_edge(0, 2);
+
+        assert!(!g.has_eulerian_circuit());
+        assert!(g.has_eulerian_path());
+    }
+
+    #[test]
+    fn graph_with_four_odd_degrees_has_no_eulerian_walk() {
+        assert!(!diamond().has_eulerian_circuit());
+        assert!(!diamond().has_eulerian_path());
+        assert!(!tree().has_eulerian_path());
+    }
+
+    #[test]
+    fn disconnected_edges_have_no_eulerian_walk() {
+        let mut g = Graph::new(6);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        g.add_edge(3, 4);
+        g.add_edge(4, 5);
+        g.add_edge(5, 3);
+
+        assert!(!g.has_eulerian_circuit());
+        assert!(!g.has_eulerian_path());
+    }
+
+    fn edges(g: &Graph) -> Vec<(usize, usize)> {
+        let mut edges: Vec<_> = (0..g.vertex_count())
+            .flat_map(|a| g.neighbours(a).iter().map(move |&b| (a, b)))
+            .filter(|(a, b)| a < b)
+            .collect();
+        edges.sort();
+
+        edges
+    }
+
+    #[test]
+    fn it_recognizes_trees() {
+        // The fixture has an isolated vertex, so it's a forest.
+        assert!(!tree().is_tree());
+        assert!(!cycle(3).is_tree());
+        assert!(!Graph::new(0).is_tree());
+        assert!(Graph::new(1).is_tree());
+
+        let mut g = tree();
+        g.add_edge(5, 6);
+        assert!(g.is_tree());
+    }
+
+    #[test]
+    fn prufer_encodes_tree() {
+        let mut g = tree();
+        g.add_edge(5, 6);
+
+        // Leaves are removed in order 3, 4, 1, 0, 2.
+        assert_eq!(g.prufer_encode(), Some(vec![1, 1, 0, 2, 5]));
+    }
+
+    #[test]
+    fn prufer_encode_rejects_non_trees() {
+        assert_eq!(tree().prufer_encode(), None);
+        assert_eq!(cycle(4).prufer_encode(), None);
+        assert_eq!(Graph::new(1).prufer_encode(), None);
+    }
+
+    #[test]
+    fn prufer_decode_reverses_encode() {
+        let mut g = tree();
+        g.add_edge(5, 6);
+
+        let decoded = prufer_decode(&g.prufer_encode().unwrap(), 7);
+
+        assert_eq!(edges(&decoded), edges(&g));
+    }
+
+    #[test]
+    fn prufer_sequences_are_bijection() {
+        let n = 5;
+        let mut trees = Vec::new();
+
+        for code in 0..n * n * n {
+            let seq = vec![code % n, code / n % n, code / n / n];
+
+            let tree = prufer_decode(&seq, n);
+
+            assert!(tree.is_tree());
+            assert_eq!(tree.prufer_encode(), Some(seq));
+            trees.push(edges(&tree));
+        }
+
+        trees.sort();
+        trees.dedup();
+        assert_eq!(trees.len(), 125);
+    }
+
+    #[test]
+    fn prufer_decodes_edge() {
+        assert_eq!(edges(&prufer_decode(&[], 2)), vec![(0, 1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn prufer_decode_panics_on_wrong_length() {
+        prufer_decode(&[0, 1], 3);
+    }
+
+    fn complete(n: usize) -> Graph {
+        let mut g = Graph::new(n);
+        for a in 0..n {
+            for b in a + 1..n {
+                g.add_edge(a, b);
+            }
+        }
+
+        g
+    }
+
+    #[test]
+    fn complete_graph_has_cayley_number_of_spanning_trees() {
+        assert_eq!(complete(2).spanning_tree_count(), 1);
+        assert_eq!(complete(3).spanning_tree_count(), 3);
+        assert_eq!(complete(4).spanning_tree_count(), 16);
+        assert_eq!(complete(7).spanning_tree_count(), 16807);
+    }
+
+    #[test]
+    fn tree_is_its_only_spanning_tree() {
+        let mut g = tree();
+        g.add_edge(5, 6);
+
+        assert_eq!(g.spanning_tree_count(), 1);
+        assert_eq!(Graph::new(1).spanning_tree_count(), 1);
+    }
+
+    #[test]
+    fn spanning_trees_of_other_graphs() {
+        assert_eq!(cycle(5).spanning_tree_count(), 5);
+        assert_eq!(diamond().spanning_tree_count(), 8);
+        assert_eq!(tree().spanning_tree_count(), 0);
+        assert_eq!(Graph::new(0).spanning_tree_count(), 0);
+    }
+
+    #[test]
+    fn relabeled_graph_is_isomorphic() {
+        let g = diamond();
+
+        // Maps vertex v onto vertex (3v + 1) % 5.
+        let mut relabeled = Graph::new(5);
+        for &(a, b) in &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)] {
+            relabeled.add_edge((3 * a + 1) % 5, (3 * b + 1) % 5);
+        }
+
+        assert!(g.is_isomorphic_to(&relabeled));
+        assert!(relabeled.is_isomorphic_to(&g));
+    }
+
+    #[test]
+    fn four_cycles_are_isomorphic() {
+        let mut g = Graph::new(4);
+        g.add_edge(0, 2);
+        g.add_edge(2, 1);
+        g.add_edge(1, 3);
+        g.add_edge(3, 0);
+
+        assert!(cycle(4).is_isomorphic_to(&g));
+    }
+
+    #[test]
+    fn graphs_with_different_degrees_are_not_isomorphic() {
+        // Both have four vertices and three edges.
+        let mut path = Graph::new(4);
+        path.add_edge(0, 1);
+        path.add_edge(1, 2);
+        path.add_edge(2, 3);
+        let mut star = Graph::new(4);
+        star.add_edge(0, 1);
+        star.add_edge(0, 2);
+        star.add_edge(0, 3);
+
+        assert!(!path.is_isomorphic_to(&star));
+        assert!(!cycle(4).is_isomorphic_to(&cycle(5)));
+    }
+
+    #[test]
+    fn graphs_with_same_degrees_need_not_be_isomorphic() {
+        // Two triangles and a hexagon are both 2-regular on six vertices.
+        let mut triangles = Graph::new(6);
+        for &(a, b) in &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+            triangles.add_edge(a, b);
+        }
+
+        assert!(!triangles.is_isomorphic_to(&cycle(6)));
+    }
+
+    #[test]
+    fn wheel_has_spokes_and_rim() {
+        let g = Graph::wheel(5);
+
+        assert_eq!(g.vertex_count(), 6);
+        assert_eq!(g.edge_count(), 10);
+        assert_eq!(g.degree(5), 5);
+        assert!((0..5).all(|vertex| g.degree(vertex) == 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn wheel_panics_without_cycle() {
+        Graph::wheel(2);
+    }
+
+    #[test]
+    fn complete_bipartite_has_all_edges_between_parts() {
+        let g = Graph::complete_bipartite(3, 3);
+
+        assert_eq!(g.vertex_count(), 6);
+        assert_eq!(g.edge_count(), 9);
+        assert!(g.has_edge(0, 5));
+        assert!(!g.has_edge(0, 1));
+        assert!(!g.has_edge(4, 5));
+        assert_eq!(Graph::complete_bipartite(2, 3).edge_count(), 6);
+        assert_eq!(Graph::complete_bipartite(0, 3).edge_count(), 0);
+    }
+
+    #[test]
+    fn it_recognizes_bipartite_graphs() {
+        assert!(Graph::complete_bipartite(2, 3).is_bipartite());
+        assert!(cycle(6).is_bipartite());
+        assert!(tree().is_bipartite());
+        assert!(Graph::new(0).is_bipartite());
+    }
+
+    #[test]
+    fn graphs_with_odd_cycles_are_not_bipartite() {
+        assert!(!Graph::wheel(5).is_bipartite());
+        assert!(!Graph::wheel(4).is_bipartite());
+        assert!(!cycle(5).is_bipartite());
+    }
+
+    #[test]
+    fn edge_joining_two_triangles_is_bridge() {
+        let mut g = Graph::new(6);
+        for &(a, b) in &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 4)] {
+            g.add_edge(a, b);
+        }
+
+        assert_eq!(g.bridges(), vec![(2, 4)]);
+    }
+
+    #[test]
+    fn cycle_has_no_bridges() {
+        assert!(cycle(5).bridges().is_empty());
+        assert!(Graph::wheel(4).bridges().is_empty());
+    }
+
+    #[test]
+    fn every_edge_of_forest_is_bridge() {
+        assert_eq!(
+            tree().bridges(),
+            vec![(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]
+        );
+        assert_eq!(diamond().bridges(), vec![(3, 4)]);
+    }
+
+    #[test]
+    fn complete_bipartite_graphs_have_perfect_matching() {
+        let g = Graph::complete_bipartite(2, 2);
+        let matching = g.maximum_bipartite_matching();
+        assert_eq!(matching.len(), 2);
+        assert!(matching.iter().all(|&(a, b)| a < 2 && g.has_edge(a, b)));
+
+        assert_eq!(
+            Graph::complete_bipartite(3, 3)
+                .maximum_bipartite_matching()
+                .len(),
+            3
+        );
+        assert_eq!(
+            Graph::complete_bipartite(2, 5)
+                .maximum_bipartite_matching()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn matching_needs_augmenting_paths() {
+        // Greedy matching of 0 with 3 blocks 1, unless it's rematched.
+        let mut g = Graph::new(6);
+        for &(a, b) in &[(0, 3), (0, 4), (1, 3), (2, 4), (2, 5)] {
+            g.add_edge(a, b);
+        }
+
+        let matching = g.maximum_bipartite_matching();
+
+        assert_eq!(matching.len(), 3);
+        assert!(matching.iter().all(|&(a, b)| g.has_edge(a, b)));
+    }
+
+    #[test]
+    fn matching_is_not_always_perfect() {
+        // Vertices 1 and 2 are only joined with 3, so one of them stays free.
+        let mut g = Graph::new(6);
+        for &(a, b) in &[(0, 3), (0, 4), (0, 5), (1, 3), (2, 3)] {
+            g.add_edge(a, b);
+        }
+
+        assert_eq!(g.maximum_bipartite_matching().len(), 2);
+        // Every edge of the tree has vertex 1 or 2 as an end.
+        assert_eq!(tree().maximum_bipartite_matching().len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn matching_panics_on_odd_cycle() {
+        cycle(3).maximum_bipartite_matching();
+    }
+
+    fn path(len: usize) -> Graph {
+        let mut g = Graph::new(len);
+        for vertex in 1..len {
+            g.add_edge(vertex - 1, vertex);
+        }
+
+        g
+    }
+
+    /// Longest distance between any two vertices, found by breadth first
+    /// search from each of them.
+    fn brute_force_diameter(g: &Graph) -> usize {
+        (0..g.vertex_count())
+            .map(|start| {
+                let mut distance = vec![None; g.vertex_count()];
+                let mut queue = VecDeque::new();
+                distance[start] = Some(0);
+                queue.push_back(start);
+                while let Some(vertex) = queue.pop_front() {
+                    for &neighbour in g.neighbours(vertex) {
+                        if distance[neighbour].is_none() {
+                            distance[neighbour] = distance[vertex].map(|d| d + 1);
+                            queue.push_back(neighbour);
+                        }
+                    }
+                }
+                distance.into_iter().flatten().max().unwrap_or(0)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn path_of_odd_number_of_vertices_has_one_center() {
+        let g = path(7);
+
+        assert_eq!(g.tree_center(), vec![3]);
+        assert_eq!(g.radius(), Some(3));
+        assert_eq!(g.diameter(), Some(6));
+    }
+
+    #[test]
+    fn path_of_even_number_of_vertices_has_two_centers() {
+        let g = path(6);
+
+        assert_eq!(g.tree_center(), vec![2, 3]);
+        assert_eq!(g.radius(), Some(3));
+        assert_eq!(g.diameter(), Some(5));
+    }
+
+    #[test]
+    fn diameter_is_longest_distance() {
+        let mut g = tree();
+        g.add_edge(5, 6);
+
+        assert_eq!(g.tree_center(), vec![0, 2]);
+        assert_eq!(g.diameter(), Some(brute_force_diameter(&g)));
+
+        for code in 0..125 {
+            let tree = prufer_decode(&[code % 5, code / 5 % 5, code / 25], 5);
+            assert_eq!(tree.diameter(), Some(brute_force_diameter(&tree)));
+        }
+    }
+
+    #[test]
+    fn small_trees_and_non_trees() {
+        assert_eq!(Graph::new(1).tree_center(), vec![0]);
+        assert_eq!(Graph::new(1).diameter(), Some(0));
+        assert_eq!(path(2).tree_center(), vec![0, 1]);
+        assert_eq!(path(2).radius(), Some(1));
+        assert!(tree().tree_center().is_empty());
+        assert_eq!(cycle(4).radius(), None);
+    }
+}
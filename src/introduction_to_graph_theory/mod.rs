@@ -5,3 +5,7 @@
 //! little practical use. I included them for their aesthetics.
 //!
 //! [introduction-to-graph-theory]: https://www.goodreads.com/book/show/388049.Introduction_to_Graph_Theory
+
+pub mod digraph;
+pub mod graph;
+pub mod weighted_graph;
@@ -0,0 +1,231 @@
+//! # Weighted graph
+//!
+//! A weighted graph is a [`Graph`] whose every edge is assigned a number
+//! called its _weight_. The weight can stand for a distance, a cost or a
+//! capacity. The length of a path is then the sum of weights of its edges
+//! rather than the number of its edges.
+//!
+//! [`Graph`]: ../graph/struct.Graph.html
+
+use crate::algorithms_data_structures_programs::disjoint_set::DisjointSet;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Undirected simple graph with weighted edges, represented by adjacency
+/// lists of `(neighbour, weight)` pairs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WeightedGraph {
+    adjacency: Vec<Vec<(usize, u64)>>,
+}
+
+impl WeightedGraph {
+    /// Creates a graph with given number of vertices and no edges.
+    pub fn new(vertex_count: usize) -> Self {
+        WeightedGraph {
+            adjacency: vec![Vec::new(); vertex_count],
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Joins two vertices with an edge of given weight. Loops and edges which
+    /// are already present are ignored so that the graph stays simple.
+    ///
+    /// Panics if either vertex is not in the graph.
+    pub fn add_edge(&mut self, a: usize, b: usize, weight: u64) {
+        assert!(a < self.vertex_count() && b < self.vertex_count());
+
+        if a == b || self.adjacency[a].iter().any(|&(n, _)| n == b) {
+            return;
+        }
+
+        self.adjacency[a].push((b, weight));
+        self.adjacency[b].push((a, weight));
+    }
+
+    pub fn neighbours(&self, vertex: usize) -> &[(usize, u64)] {
+        &self.adjacency[vertex]
+    }
+
+    /// Returns length of the shortest path from the source to each vertex, or
+    /// `None` for vertices which can't be reached from the source.
+    ///
+    /// Implements Dijkstra's algorithm. Vertices are settled in the order of
+    /// their distance from the source. The closest unsettled vertex is taken
+    /// from a min-heap of tentative distances. Its distance is final, because
+    /// weights are not negative and therefore any other path to it would go
+    /// through a vertex which is at least as far. Then the tentative distances
+    /// of its neighbours are relaxed.
+    ///
+    /// A path whose length doesn't fit into `u64` is ignored, so a vertex
+    /// which can only be reached by such paths is reported as unreachable.
+    pub fn dijkstra(&self, source: usize) -> Vec<Option<u64>> {
+        let mut distances = vec![None; self.vertex_count()];
+        // Standard library heap is a max-heap. Reverse makes it a min-heap.
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((0u64, source)));
+
+        while let Some(Reverse((distance, vertex))) = heap.pop() {
+            // The heap may contain outdated entries for settled vertices.
+            if distances[vertex].is_some() {
+                continue;
+            }
+
+            distances[vertex] = Some(distance);
+
+            for &(neighbour, weight) in &self.adjacency[vertex] {
+                if distances[neighbour].is_some() {
+                    continue;
+                }
+
+                if let Some(through_vertex) = distance.checked_add(weight) {
+                    heap.push(Reverse((through_vertex, neighbour)));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Returns edges of a minimum spanning tree as `(a, b, weight)` triples,
+    /// where `a < b`. If the graph is not connected, returns a minimum
+    /// spanning forest, i.e. a minimum spanning tree of each component.
+    ///
+    /// Implements Kruskal's algorithm. Edges are visited from the lightest.
+    /// An edge is added to the tree unless it would close a cycle, which is
+    /// the case when both of its ends are already in the same component of
+    /// the tree. Components are tracked in a [`DisjointSet`].
+    ///
+    /// [`DisjointSet`]: ../../algorithms_data_structures_programs/disjoint_set/struct.DisjointSet.html
+    pub fn minimum_spanning_tree(&self) -> Vec<(usize, usize, u64)> {
+        let mut edges: Vec<(usize, usize, u64)> = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(a, neighbours)| {
+                neighbours
+                    .iter()
+                    .filter(move |&&(b, _)| a < b)
+                    .map(move |&(b, weight)| (a, b, weight))
+            })
+            .collect();
+        edges.sort_by_key(|&(_, _, weight)| weight);
+
+        let mut components = DisjointSet::new(self.vertex_count());
+        let mut tree = Vec::with_capacity(self.vertex_count().saturating_sub(1));
+
+        for (a, b, weight) in edges {
+            if components.union(a, b) {
+                tree.push((a, b, weight));
+            }
+        }
+
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///     0 --7-- 1
+    ///     | \     |
+    ///     9  14   10
+    ///     |   \   |
+    ///     2 --2-- 3       4
+    fn fixture() -> WeightedGraph {
+        let mut g = WeightedGraph::new(5);
+        g.add_edge(0, 1, 7);
+        g.add_edge(0, 2, 9);
+        g.add_edge(0, 3, 14);
+        g.add_edge(1, 3, 10);
+        g.add_edge(2, 3, 2);
+
+        g
+    }
+
+    #[test]
+    fn dijkstra_finds_shortest_distances() {
+        let g = fixture();
+
+        assert_eq!(
+            g.dijkstra(0),
+            vec![Some(0), Some(7), Some(9), Some(11), None]
+        );
+        assert_eq!(
+            g.dijkstra(1),
+            vec![Some(7), Some(0), Some(12), Some(10), None]
+        );
+    }
+
+    #[test]
+    fn dijkstra_from_isolated_vertex() {
+        let g = fixture();
+
+        assert_eq!(g.dijkstra(4), vec![None, None, None, None, Some(0)]);
+    }
+
+    #[test]
+    fn dijkstra_skips_paths_which_overflow() {
+        let mut g = WeightedGraph::new(4);
+        g.add_edge(0, 1, u64::MAX);
+        g.add_edge(1, 2, 1);
+        g.add_edge(0, 3, u64::MAX - 1);
+        g.add_edge(3, 2, 1);
+
+        assert_eq!(
+            g.dijkstra(0),
+            vec![Some(0), Some(u64::MAX), Some(u64::MAX), Some(u64::MAX - 1)]
+        );
+
+        let mut unreachable = WeightedGraph::new(3);
+        unreachable.add_edge(0, 1, u64::MAX);
+        unreachable.add_edge(1, 2, 1);
+
+        assert_eq!(unreachable.dijkstra(0), vec![Some(0), Some(u64::MAX), None]);
+    }
+
+    #[test]
+    fn it_stays_simple() {
+        let mut g = fixture();
+
+        g.add_edge(0, 0, 1);
+        g.add_edge(1, 0, 1);
+
+        assert_eq!(g.neighbours(0), &[(1, 7), (2, 9), (3, 14)]);
+    }
+
+    #[test]
+    fn minimum_spanning_tree_of_connected_graph() {
+        let mut g = WeightedGraph::new(6);
+        g.add_edge(0, 1, 4);
+        g.add_edge(0, 2, 4);
+        g.add_edge(1, 2, 2);
+        g.add_edge(2, 3, 3);
+        g.add_edge(2, 4, 2);
+        g.add_edge(2, 5, 4);
+        g.add_edge(3, 5, 3);
+        g.add_edge(4, 5, 3);
+
+        let tree = g.minimum_spanning_tree();
+
+        assert_eq!(tree.len(), g.vertex_count() - 1);
+        assert_eq!(tree.iter().map(|&(_, _, w)| w).sum::<u64>(), 14);
+    }
+
+    #[test]
+    fn minimum_spanning_forest_of_disconnected_graph() {
+        let g = fixture();
+
+        let tree = g.minimum_spanning_tree();
+
+        assert_eq!(tree, vec![(2, 3, 2), (0, 1, 7), (0, 2, 9)]);
+    }
+
+    #[test]
+    fn minimum_spanning_tree_of_empty_graph() {
+        assert!(WeightedGraph::new(0).minimum_spanning_tree().is_empty());
+    }
+}
@@ -1,3 +1,7 @@
+// Citations from books are formatted as block quotes whose continuation lines
+// are indented rather than prefixed with `>`. See README.
+#![allow(clippy::doc_lazy_continuation)]
+
 pub mod algorithms_data_structures_programs;
 pub mod introduction_to_graph_theory;
 pub mod problems;
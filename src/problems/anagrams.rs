@@ -0,0 +1,70 @@
+//! # Problem
+//! Given an array of words **W**, group together the words which are anagrams
+//! of each other, i.e. which consist of the same letters, each used the same
+//! number of times.
+//!
+//! ## Example
+//! Given `W = ["eat", "tea", "tan", "ate", "nat", "bat"]`, output
+//! `[["eat", "tea", "ate"], ["tan", "nat"], ["bat"]]`.
+
+use crate::algorithms_data_structures_programs::a_005_shell_sort::shell_sort;
+use std::collections::HashMap;
+
+/// Solves the problem in space O(N) and in time of sorting each word.
+///
+/// Sorting the bytes of a word gives its signature. All anagrams share the
+/// same signature, because they only differ in the order of their letters.
+/// The signature is then a key to a hash map which remembers which group each
+/// signature belongs to. Groups are in the order of their first word and words
+/// within a group keep their input order.
+///
+/// Letters are compared byte by byte, therefore words with multibyte
+/// characters are grouped by their UTF-8 encoding.
+pub fn group_anagrams(words: &[&str]) -> Vec<Vec<String>> {
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    let mut group_of_signature = HashMap::new();
+
+    for word in words {
+        let mut signature = word.as_bytes().to_vec();
+        shell_sort(&mut signature);
+
+        let group = *group_of_signature.entry(signature).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(word.to_string());
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        assert!(group_anagrams(&[]).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let mut groups = group_anagrams(&["eat", "tea", "tan", "ate", "nat", "bat"]);
+        for group in groups.iter_mut() {
+            group.sort();
+        }
+        groups.sort();
+
+        assert_eq!(
+            groups,
+            vec![vec!["ate", "eat", "tea"], vec!["bat"], vec!["nat", "tan"],]
+        );
+    }
+
+    #[test]
+    fn letter_counts_matter() {
+        let groups = group_anagrams(&["aab", "abb", "bab", "", ""]);
+
+        assert_eq!(groups, vec![vec!["aab"], vec!["abb", "bab"], vec!["", ""]]);
+    }
+}
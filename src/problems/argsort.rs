@@ -0,0 +1,199 @@
+//! # Problem
+//! Given an array **A**, find the order in which its elements would be if it
+//! was sorted, without reordering **A** itself. Output an array of indices
+//! **P** such that `A[P[0]], A[P[1]], ...` is sorted in ascending order. Equal
+//! elements keep their relative order. The same **P** can then reorder other
+//! arrays whose elements correspond to those of **A**.
+//!
+//! ## Example
+//! Given `A = [30, 10, 20, 10]`, output `[1, 3, 2, 0]`.
+
+use std::cmp::Ordering;
+
+/// Sorts indices of the array by the elements they point to. The sort is
+/// stable, therefore indices of equal elements stay in ascending order.
+///
+/// Elements which can't be compared even with themselves, such as `NaN`, go
+/// last, in ascending order of their indices. This keeps the comparator a
+/// total order, which the standard sort requires. Panics if two other
+/// elements can't be compared.
+pub fn argsort<T>(array: &[T]) -> Vec<usize>
+where
+    T: PartialOrd,
+{
+    let comparable: Vec<bool> = array
+        .iter()
+        .map(|element| element.partial_cmp(element).is_some())
+        .collect();
+    let mut indices: Vec<usize> = (0..array.len()).collect();
+
+    indices.sort_by(|&a, &b| match (comparable[a], comparable[b]) {
+        (true, true) => array[a]
+            .partial_cmp(&array[b])
+            .expect("elements can't be compared"),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => Ordering::Equal,
+    });
+
+    indices
+}
+
+/// Reorders the array so that the element on index `i` is the one which was
+/// on index `perm[i]`. Applying the output of [`argsort`] therefore sorts the
+/// array.
+///
+/// Every permutation decomposes into disjoint cycles. Each cycle is rotated
+/// by swaps along it, which places every element in its final position with
+/// a single swap. That's O(N) swaps. A scratch array of flags remembers which
+/// positions have already been placed.
+///
+/// ```text
+/// perm = [2, 0, 1]    cycle 0 <- 2 <- 1 <- 0
+///
+/// [a, b, c]  swap(0, 2)  [c, b, a]  swap(2, 1)  [c, a, b]
+/// ```
+///
+/// Panics if `perm` is not a permutation of `0..array.len()`.
+///
+/// [`argsort`]: fn.argsort.html
+pub fn apply_permutation<T>(array: &mut [T], perm: &[usize]) {
+    assert_eq!(array.len(), perm.len(), "permutation has wrong length");
+
+    let mut seen = vec![false; perm.len()];
+    for &index in perm {
+        assert!(index < perm.len() && !seen[index], "not a permutation");
+        seen[index] = true;
+    }
+
+    // Reuses the scratch array. Now `true` means that the position hasn't
+    // been placed yet.
+    for start in 0..array.len() {
+        if !seen[start] {
+            continue;
+        }
+
+        // Position `current` is always holding the element which was
+        // originally on the start of the cycle.
+        let mut current = start;
+        while perm[current] != start {
+            array.swap(current, perm[current]);
+            seen[current] = false;
+            current = perm[current];
+        }
+        seen[current] = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert!(argsort(&array).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let array = [30, 10, 20, 10];
+
+        assert_eq!(argsort(&array), vec![1, 3, 2, 0]);
+        assert_eq!(array, [30, 10, 20, 10]);
+    }
+
+    #[test]
+    fn it_is_stable() {
+        let array = [2, 1, 2, 1, 2, 1];
+
+        assert_eq!(argsort(&array), vec![1, 3, 5, 0, 2, 4]);
+    }
+
+    #[test]
+    fn permuted_array_is_sorted() {
+        let mut array: Vec<u32> = (0..50).collect();
+        shuffle(&mut array, 5);
+
+        let permuted: Vec<u32> = argsort(&array).into_iter().map(|i| array[i]).collect();
+
+        assert!(is_sorted(&permuted));
+    }
+
+    #[test]
+    fn it_puts_nan_last() {
+        let array = [2.0, f64::NAN, 1.0, f64::NAN, 0.5];
+
+        assert_eq!(argsort(&array), vec![4, 2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn it_sorts_long_array_with_nans() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let mut rng = SeededRng::new(seed);
+            let array: Vec<f64> = (0..21 + rng.below(100))
+                .map(|_| match rng.below(4) {
+                    0 => f64::NAN,
+                    _ => rng.below(10) as f64,
+                })
+                .collect();
+
+            let indices = argsort(&array);
+            let nans = array.iter().filter(|x| x.is_nan()).count();
+            let (numbers, tail) = indices.split_at(array.len() - nans);
+
+            let sorted: Vec<f64> = numbers.iter().map(|&i| array[i]).collect();
+            assert!(is_sorted(&sorted));
+            assert!(tail.iter().all(|&i| array[i].is_nan()));
+            assert!(tail.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn apply_permutation_rotates_cycle() {
+        let mut array = ['a', 'b', 'c'];
+
+        apply_permutation(&mut array, &[2, 0, 1]);
+
+        assert_eq!(array, ['c', 'a', 'b']);
+    }
+
+    #[test]
+    fn apply_permutation_with_argsort_sorts() {
+        for seed in 0..10 {
+            let mut array: Vec<u32> = (0..50).map(|x| x / 3).collect();
+            shuffle(&mut array, seed);
+            let mut expected = array.clone();
+            expected.sort();
+
+            let perm = argsort(&array);
+            apply_permutation(&mut array, &perm);
+
+            assert_eq!(array, expected);
+        }
+    }
+
+    #[test]
+    fn apply_permutation_reorders_parallel_array() {
+        let keys = [30, 10, 20];
+        let mut names = ["thirty", "ten", "twenty"];
+
+        apply_permutation(&mut names, &argsort(&keys));
+
+        assert_eq!(names, ["ten", "twenty", "thirty"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn apply_permutation_rejects_duplicate_index() {
+        apply_permutation(&mut [1, 2, 3], &[0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn apply_permutation_rejects_wrong_length() {
+        apply_permutation(&mut [1, 2, 3], &[0, 1]);
+    }
+}
@@ -0,0 +1,113 @@
+//! # Problem
+//! Given an array **A** which represents a cyclic sequence, output its
+//! rotation which is the smallest in lexicographic order. All rotations of
+//! the same sequence have the same smallest rotation, so it serves as their
+//! canonical form: two arrays are rotations of each other exactly if their
+//! canonical rotations are equal.
+//!
+//! ## Example
+//! Given `A = [3, 1, 2]`, output **`[1, 2, 3]`**. Given `A = [2, 1, 2, 1, 1]`,
+//! output **`[1, 1, 2, 1, 2]`**.
+
+use std::cmp::Ordering;
+
+/// Solves the problem in space O(N) for the output and time O(N).
+///
+/// Two candidate starting indices `i` and `j` are compared by walking `k`
+/// elements along both rotations at once. When the rotations first differ,
+/// the one with the larger element loses. Not only its start, but also the
+/// next `k` starts lose: each of them is beaten by the matching start of the
+/// other rotation. The loser therefore skips `k + 1` indices ahead. Each step
+/// either extends `k` or moves a candidate forward by as much as `k` has
+/// grown, so there are O(N) steps. Once a candidate runs past the end, the
+/// other one is the smallest rotation.
+pub fn canonical_rotation<T>(array: &[T]) -> Vec<T>
+where
+    T: Ord + Clone,
+{
+    let n = array.len();
+    let (mut i, mut j, mut k) = (0, 1, 0);
+
+    while i < n && j < n && k < n {
+        match array[(i + k) % n].cmp(&array[(j + k) % n]) {
+            Ordering::Equal => {
+                k += 1;
+                continue;
+            }
+            Ordering::Greater => i += k + 1,
+            Ordering::Less => j += k + 1,
+        }
+
+        if i == j {
+            j += 1;
+        }
+        k = 0;
+    }
+
+    let start = i.min(j);
+    let mut rotation = Vec::with_capacity(n);
+    if n > 0 {
+        rotation.extend_from_slice(&array[start..]);
+        rotation.extend_from_slice(&array[..start]);
+    }
+
+    rotation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problems::is_rotation::is_rotation;
+
+    fn brute_force(array: &[u8]) -> Vec<u8> {
+        (0..array.len())
+            .map(|shift| {
+                let mut rotation = array.to_vec();
+                rotation.rotate_left(shift);
+                rotation
+            })
+            .min()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert!(canonical_rotation(&array).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(canonical_rotation(&[3, 1, 2]), vec![1, 2, 3]);
+        assert_eq!(canonical_rotation(&[2, 1, 2, 1, 1]), vec![1, 1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn all_rotations_have_same_canonical_form() {
+        assert_eq!(canonical_rotation(&[1, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(canonical_rotation(&[2, 3, 1]), vec![1, 2, 3]);
+        assert_eq!(canonical_rotation(&[3, 1, 2]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn it_handles_periodic_arrays() {
+        assert_eq!(canonical_rotation(&[4, 4, 4]), vec![4, 4, 4]);
+        assert_eq!(canonical_rotation(&[2, 1, 2, 1]), vec![1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn it_agrees_with_brute_force() {
+        // All arrays of length 6 over a three letter alphabet.
+        for code in 0..729u32 {
+            let array: Vec<u8> = (0..6)
+                .map(|digit| (code / 3u32.pow(digit) % 3) as u8)
+                .collect();
+
+            let canonical = canonical_rotation(&array);
+
+            assert_eq!(canonical, brute_force(&array));
+            assert!(is_rotation(&array, &canonical));
+        }
+    }
+}
@@ -21,36 +21,147 @@
 //!
 //! Given nodes `n5` and `n6`, the output of a correct algorithm should be `n2`.
 
+use std::cell::RefCell;
 use std::rc::Rc;
 
-pub struct Node {
-    left: Option<Rc<Node>>,
-    right: Option<Rc<Node>>,
+/// A node of a binary tree. The problem doesn't need nodes to carry any value,
+/// hence the value type defaults to unit. Other tree algorithms use the value.
+#[derive(Default)]
+pub struct Node<T = ()> {
+    value: T,
+    left: Option<Rc<Node<T>>>,
+    right: Option<Rc<Node<T>>>,
 }
 
 impl Node {
     pub fn new(left: &Rc<Node>, right: &Rc<Node>) -> Self {
         Node {
+            value: (),
             left: Some(Rc::clone(left)),
             right: Some(Rc::clone(right)),
         }
     }
 }
 
-impl Default for Node {
-    fn default() -> Self {
+impl<T> Node<T> {
+    pub fn with_value(value: T, left: Option<&Rc<Node<T>>>, right: Option<&Rc<Node<T>>>) -> Self {
         Node {
+            value,
+            left: left.map(Rc::clone),
+            right: right.map(Rc::clone),
+        }
+    }
+
+    pub fn leaf(value: T) -> Self {
+        Node {
+            value,
             left: None,
             right: None,
         }
     }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns values of the subtree in pre-order: each node is visited before
+    /// its left subtree, which is visited before its right subtree.
+    ///
+    /// ```text
+    ///         1
+    ///        / \
+    ///       2   5
+    ///      / \
+    ///     3   4
+    /// ```
+    ///
+    /// Iterates instead of recursing so that deep trees don't overflow the
+    /// call stack.
+    pub fn pre_order(&self) -> Vec<&T> {
+        let mut values = Vec::new();
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            values.push(&node.value);
+
+            // Right child is pushed first so that the left one is popped
+            // first.
+            if let Some(ref right) = node.right {
+                stack.push(right);
+            }
+            if let Some(ref left) = node.left {
+                stack.push(left);
+            }
+        }
+
+        values
+    }
+
+    /// Returns values of the subtree in post-order: each node is visited after
+    /// its left subtree, which is visited before its right subtree.
+    ///
+    /// ```text
+    ///         5
+    ///        / \
+    ///       3   4
+    ///      / \
+    ///     1   2
+    /// ```
+    ///
+    /// Reversed post-order visits a node before its right subtree, which is
+    /// visited before its left subtree. That's pre-order with the children
+    /// swapped, which is easy to do with a single stack. The result is then
+    /// reversed.
+    pub fn post_order(&self) -> Vec<&T> {
+        let mut values = Vec::new();
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            values.push(&node.value);
+
+            if let Some(ref left) = node.left {
+                stack.push(left);
+            }
+            if let Some(ref right) = node.right {
+                stack.push(right);
+            }
+        }
+
+        values.reverse();
+        values
+    }
+}
+
+/// Dropping a node drops its children, which would recurse once per level of
+/// the tree and overflow the call stack on long chains, such as the output of
+/// [`flatten_to_right_list`]. Instead, the children are detached and dropped
+/// in a loop. A child which is still shared elsewhere only loses a reference.
+///
+/// [`flatten_to_right_list`]: fn.flatten_to_right_list.html
+impl<T> Drop for Node<T> {
+    fn drop(&mut self) {
+        let mut detached: Vec<Rc<Node<T>>> = Vec::new();
+        detached.extend(self.left.take());
+        detached.extend(self.right.take());
+
+        while let Some(node) = detached.pop() {
+            if let Ok(mut node) = Rc::try_unwrap(node) {
+                detached.extend(node.left.take());
+                detached.extend(node.right.take());
+            }
+        }
+    }
 }
 
 ///
 ///
 ///
 /// PS: Implemented the whole module in one go and all tests passed first time.
-pub fn closest_common_ancestor(root: &Rc<Node>, n1: &Rc<Node>, n2: &Rc<Node>) -> Option<Rc<Node>> {
+pub fn closest_common_ancestor<T>(
+    root: &Rc<Node<T>>,
+    n1: &Rc<Node<T>>,
+    n2: &Rc<Node<T>>,
+) -> Option<Rc<Node<T>>> {
     // If either node is equal to the root node, return none as it goes against
     // logic for a node to be ancestor of itself and there is no ancestor for
     // root.
@@ -94,24 +205,24 @@ pub fn closest_common_ancestor(root: &Rc<Node>, n1: &Rc<Node>, n2: &Rc<Node>) ->
         }
     }
 
-    Some(Rc::clone(&node))
+    Some(Rc::clone(node))
 }
 
-fn index_of_two_nodes(
-    a: &Rc<Node>,
-    b: &Rc<Node>,
-    node: &Rc<Node>,
+fn index_of_two_nodes<T>(
+    a: &Rc<Node<T>>,
+    b: &Rc<Node<T>>,
+    node: &Rc<Node<T>>,
     index: usize,
 ) -> (Option<usize>, Option<usize>) {
     if Rc::ptr_eq(a, node) {
         let index_b = node
             .left
             .as_ref()
-            .map(|ref child| index_of_one_node(b, child, index * 2))
-            .unwrap_or_else(|| {
+            .and_then(|child| index_of_one_node(b, child, index * 2))
+            .or_else(|| {
                 node.right
                     .as_ref()
-                    .and_then(|ref child| index_of_one_node(b, child, index * 2 + 1))
+                    .and_then(|child| index_of_one_node(b, child, index * 2 + 1))
             });
 
         (Some(index), index_b)
@@ -119,11 +230,11 @@ fn index_of_two_nodes(
         let index_a = node
             .left
             .as_ref()
-            .map(|ref child| index_of_one_node(a, child, index * 2))
-            .unwrap_or_else(|| {
+            .and_then(|child| index_of_one_node(a, child, index * 2))
+            .or_else(|| {
                 node.right
                     .as_ref()
-                    .and_then(|ref child| index_of_one_node(a, child, index * 2 + 1))
+                    .and_then(|child| index_of_one_node(a, child, index * 2 + 1))
             });
 
         (index_a, Some(index))
@@ -144,8 +255,8 @@ fn index_of_two_nodes(
     }
 }
 
-fn index_of_one_node(target: &Rc<Node>, node: &Rc<Node>, index: usize) -> Option<usize> {
-    if Rc::ptr_eq(&target, &node) {
+fn index_of_one_node<T>(target: &Rc<Node<T>>, node: &Rc<Node<T>>, index: usize) -> Option<usize> {
+    if Rc::ptr_eq(target, node) {
         Some(index)
     } else {
         let index_left = if let Some(ref child) = node.left {
@@ -164,6 +275,341 @@ fn index_of_one_node(target: &Rc<Node>, node: &Rc<Node>, index: usize) -> Option
     }
 }
 
+/// Finds the closest common ancestor without encoding node positions as heap
+/// indices. The index approach doubles the index with each level, so it
+/// overflows on trees deeper than the bit width of `usize`. This version works
+/// for arbitrarily shaped trees.
+///
+/// It first finds the path from the root to each node. Node on index `i` of a
+/// path is the ancestor on depth `i`, hence both paths agree up to the
+/// closest common ancestor and differ below it. Starting at the depth of the
+/// shallower node, both paths are walked up in lockstep until they meet.
+///
+/// The edge cases behave the same as in [`closest_common_ancestor`].
+///
+/// [`closest_common_ancestor`]: fn.closest_common_ancestor.html
+pub fn closest_common_ancestor_by_depth<T>(
+    root: &Rc<Node<T>>,
+    n1: &Rc<Node<T>>,
+    n2: &Rc<Node<T>>,
+) -> Option<Rc<Node<T>>> {
+    if Rc::ptr_eq(root, n1) || Rc::ptr_eq(root, n2) {
+        return None;
+    }
+
+    let n1_path = path_to(root, n1)?;
+    let n2_path = path_to(root, n2)?;
+
+    // The root is in both paths, therefore this loop always terminates.
+    let mut depth = (n1_path.len() - 1).min(n2_path.len() - 1);
+    while !Rc::ptr_eq(&n1_path[depth], &n2_path[depth]) {
+        depth -= 1;
+    }
+
+    Some(Rc::clone(&n1_path[depth]))
+}
+
+/// Returns how many edges separate given node from the root, or `None` if the
+/// node is not in the tree.
+pub fn depth_of<T>(root: &Rc<Node<T>>, target: &Rc<Node<T>>) -> Option<usize> {
+    path_to(root, target).map(|path| path.len() - 1)
+}
+
+/// Returns all nodes on the way from the root to the target, both inclusive.
+/// If the target is not in the tree, returns `None`.
+///
+/// Depth first search keeps the current path on a stack together with how
+/// many children of each node on it have been tried. It iterates instead of
+/// recursing so that deep trees don't overflow the call stack.
+pub fn path_to<T>(root: &Rc<Node<T>>, target: &Rc<Node<T>>) -> Option<Vec<Rc<Node<T>>>> {
+    let mut path: Vec<(Rc<Node<T>>, u8)> = vec![(Rc::clone(root), 0)];
+
+    while let Some((node, tried)) = path.last_mut() {
+        if Rc::ptr_eq(node, target) {
+            return Some(path.into_iter().map(|(node, _)| node).collect());
+        }
+
+        let child = match *tried {
+            0 => node.left.clone(),
+            1 => node.right.clone(),
+            // Both subtrees have been searched, so this node is not on the
+            // path.
+            _ => {
+                path.pop();
+                continue;
+            }
+        };
+        *tried += 1;
+
+        if let Some(child) = child {
+            path.push((child, 0));
+        }
+    }
+
+    None
+}
+
+/// Collects values of the tree in in-order: each node is visited after its
+/// left subtree and before its right subtree. If the tree is a binary search
+/// tree, the values come out sorted.
+pub fn bst_to_sorted_vec<T: Clone>(root: &Rc<Node<T>>) -> Vec<T> {
+    let mut values = Vec::new();
+    let mut stack: Vec<&Node<T>> = Vec::new();
+    let mut current = Some(&**root);
+
+    while current.is_some() || !stack.is_empty() {
+        // Descends as far left as possible, remembering the way back.
+        while let Some(node) = current {
+            stack.push(node);
+            current = node.left.as_deref();
+        }
+
+        // The left subtree of the node on top of the stack has been visited.
+        let node = stack.pop().unwrap();
+        values.push(node.value.clone());
+        current = node.right.as_deref();
+    }
+
+    values
+}
+
+/// A node of a binary tree whose right link can be rewritten through a shared
+/// reference. [`morris_in_order`] needs that to thread the tree in place,
+/// which nodes of [`Node`] don't allow.
+///
+/// [`morris_in_order`]: fn.morris_in_order.html
+/// [`Node`]: struct.Node.html
+pub struct ThreadedNode<T> {
+    value: T,
+    left: Option<Rc<ThreadedNode<T>>>,
+    right: RefCell<Option<Rc<ThreadedNode<T>>>>,
+}
+
+impl<T> ThreadedNode<T> {
+    pub fn with_value(
+        value: T,
+        left: Option<&Rc<ThreadedNode<T>>>,
+        right: Option<&Rc<ThreadedNode<T>>>,
+    ) -> Self {
+        ThreadedNode {
+            value,
+            left: left.map(Rc::clone),
+            right: RefCell::new(right.map(Rc::clone)),
+        }
+    }
+
+    pub fn leaf(value: T) -> Self {
+        Self::with_value(value, None, None)
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Collects values of the tree in in-order, like [`bst_to_sorted_vec`], but
+/// without a stack. This is Morris traversal.
+///
+/// Instead of remembering the way back on a stack, the traversal threads it
+/// through the tree. Before descending into the left subtree of a node, the
+/// rightmost node of that subtree, which is the node's in-order predecessor,
+/// gets a temporary right link back to the node. When the traversal later
+/// follows that link up, it removes it again and visits the node. Each edge
+/// is walked at most three times, so the traversal runs in O(N), and apart
+/// from the output it only holds a couple of pointers.
+///
+/// Threading rewrites right links of a tree which is shared behind [`Rc`],
+/// hence the tree is made of [`ThreadedNode`]s whose right link is in a
+/// [`RefCell`]. All threads are removed by the time the traversal returns, so
+/// the tree is left as it was. Should cloning a value panic halfway, the
+/// threads stay and the reference cycles they form leak the tree.
+///
+/// [`bst_to_sorted_vec`]: fn.bst_to_sorted_vec.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`ThreadedNode`]: struct.ThreadedNode.html
+/// [`RefCell`]: https://doc.rust-lang.org/std/cell/struct.RefCell.html
+pub fn morris_in_order<T: Clone>(root: &Rc<ThreadedNode<T>>) -> Vec<T> {
+    let mut values = Vec::new();
+    let mut current = Some(Rc::clone(root));
+
+    while let Some(node) = current {
+        let left = match node.left {
+            Some(ref left) => Rc::clone(left),
+            None => {
+                values.push(node.value.clone());
+                current = node.right.borrow().clone();
+                continue;
+            }
+        };
+
+        // Finds the in-order predecessor, stopping at the thread if it
+        // already leads back to the node.
+        let mut predecessor = Rc::clone(&left);
+        loop {
+            let next = predecessor.right.borrow().clone();
+            match next {
+                Some(next) if !Rc::ptr_eq(&next, &node) => predecessor = next,
+                _ => break,
+            }
+        }
+
+        let mut predecessor_right = predecessor.right.borrow_mut();
+        if predecessor_right.is_none() {
+            *predecessor_right = Some(Rc::clone(&node));
+            current = Some(left);
+        } else {
+            // The left subtree has been visited, the thread is no longer
+            // needed.
+            *predecessor_right = None;
+            values.push(node.value.clone());
+            current = node.right.borrow().clone();
+        }
+    }
+
+    values
+}
+
+/// Counts nodes which are exactly `depth` edges below the root. The root
+/// itself is on depth 0.
+///
+/// Depth first search carries the depth of each node on the stack and
+/// doesn't descend below the requested depth.
+pub fn nodes_at_depth<T>(root: &Rc<Node<T>>, depth: usize) -> usize {
+    let mut count = 0;
+    let mut stack: Vec<(&Node<T>, usize)> = vec![(root, 0)];
+
+    while let Some((node, node_depth)) = stack.pop() {
+        if node_depth == depth {
+            count += 1;
+            continue;
+        }
+
+        for child in node.left.iter().chain(node.right.iter()) {
+            stack.push((child, node_depth + 1));
+        }
+    }
+
+    count
+}
+
+/// Returns the largest number of nodes on a single level of the tree.
+///
+/// The tree is traversed level by level. All nodes of a level are kept in a
+/// vector, from which the vector of their children, the next level, is made.
+pub fn max_width<T>(root: &Rc<Node<T>>) -> usize {
+    let mut width = 0;
+    let mut level: Vec<&Node<T>> = vec![root];
+
+    while !level.is_empty() {
+        width = width.max(level.len());
+
+        level = level
+            .iter()
+            .flat_map(|node| node.left.iter().chain(node.right.iter()))
+            .map(|child| &**child)
+            .collect();
+    }
+
+    width
+}
+
+/// Returns the `k`-th smallest value of a binary search tree, counting from
+/// one, or `None` if the tree has fewer than `k` nodes.
+///
+/// Walks the tree in in-order like [`bst_to_sorted_vec`], but stops as soon
+/// as the `k`-th node is visited. That takes time O(H + k) instead of O(N),
+/// where H is the height of the tree.
+///
+/// [`bst_to_sorted_vec`]: fn.bst_to_sorted_vec.html
+pub fn kth_smallest_bst<T: Clone>(root: &Rc<Node<T>>, k: usize) -> Option<T> {
+    let mut visited = 0;
+    let mut stack: Vec<&Node<T>> = Vec::new();
+    let mut current = Some(&**root);
+
+    while current.is_some() || !stack.is_empty() {
+        while let Some(node) = current {
+            stack.push(node);
+            current = node.left.as_deref();
+        }
+
+        let node = stack.pop()?;
+        visited += 1;
+        if visited == k {
+            return Some(node.value.clone());
+        }
+        current = node.right.as_deref();
+    }
+
+    None
+}
+
+/// Returns a copy of the tree rearranged into a chain in which each node's
+/// right child is the next node in pre-order and no node has a left child.
+///
+/// The original tree is shared behind [`Rc`], so it can't be relinked in
+/// place. The chain is built from new nodes instead, starting from its end:
+/// each node is created with the previously created one as its right child.
+/// The chain is as long as the tree has nodes, but since nodes are dropped
+/// without recursion, that doesn't endanger the call stack.
+///
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+pub fn flatten_to_right_list<T: Clone>(root: &Rc<Node<T>>) -> Rc<Node<T>> {
+    let values = root.pre_order();
+    let (first, rest) = values.split_first().expect("tree has a root");
+
+    let mut tail: Option<Rc<Node<T>>> = None;
+    for value in rest.iter().rev() {
+        tail = Some(Rc::new(Node::with_value(
+            (*value).clone(),
+            None,
+            tail.as_ref(),
+        )));
+    }
+
+    Rc::new(Node::with_value((*first).clone(), None, tail.as_ref()))
+}
+
+/// Returns whether each node's value is larger than all values in its left
+/// subtree and smaller than all values in its right subtree.
+///
+/// Comparing a node only with its children is not enough. In the tree below,
+/// each node is ordered with respect to its children, yet `5` is in the left
+/// subtree of `4`.
+///
+/// ```text
+///         4
+///        /
+///       2
+///        \
+///         5
+/// ```
+///
+/// Therefore each node is checked against bounds which are inherited from its
+/// ancestors. Going left tightens the upper bound, going right tightens the
+/// lower bound.
+pub fn is_valid_bst<T: PartialOrd>(root: &Rc<Node<T>>) -> bool {
+    is_within_bounds(root, None, None)
+}
+
+fn is_within_bounds<T: PartialOrd>(node: &Node<T>, lower: Option<&T>, upper: Option<&T>) -> bool {
+    if lower.is_some_and(|lower| node.value <= *lower)
+        || upper.is_some_and(|upper| node.value >= *upper)
+    {
+        return false;
+    }
+
+    let left_is_valid = node
+        .left
+        .as_ref()
+        .is_none_or(|left| is_within_bounds(left, lower, Some(&node.value)));
+
+    left_is_valid
+        && node
+            .right
+            .as_ref()
+            .is_none_or(|right| is_within_bounds(right, Some(&node.value), upper))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,4 +719,420 @@ mod tests {
 
         assert!(ancestor.is_none());
     }
+
+    fn skewed_graph(depth: usize) -> Vec<Rc<Node>> {
+        // Each node in the chain has a left child which continues the chain
+        // and a right child which is a leaf.
+        let mut chain: Vec<Rc<Node>> = vec![Default::default()];
+
+        for _ in 0..depth {
+            let left = chain.last().unwrap();
+            chain.push(Rc::new(Node::new(left, &Default::default())));
+        }
+
+        chain.reverse();
+        chain
+    }
+
+    #[test]
+    fn by_depth_agrees_with_index_method() {
+        let g = balanced_graph();
+
+        for a in 1..16 {
+            for b in 1..16 {
+                let expected = closest_common_ancestor(&g[1], &g[a], &g[b]);
+                let ancestor = closest_common_ancestor_by_depth(&g[1], &g[a], &g[b]);
+
+                match (expected, ancestor) {
+                    (Some(expected), Some(ancestor)) => {
+                        assert!(Rc::ptr_eq(&expected, &ancestor))
+                    }
+                    (None, None) => (),
+                    _ => panic!("Methods disagree on nodes {} and {}", a, b),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn by_depth_returns_none_if_node_is_not_in_graph() {
+        let mut g = balanced_graph();
+        g[0] = Default::default();
+
+        assert!(closest_common_ancestor_by_depth(&g[1], &g[0], &g[2]).is_none());
+        assert!(closest_common_ancestor_by_depth(&g[1], &g[2], &g[0]).is_none());
+    }
+
+    #[test]
+    fn by_depth_solves_skewed_graph() {
+        // Heap index of the deepest node would be 2^100 which overflows usize.
+        let chain = skewed_graph(100);
+        let deep_leaf = chain[99].right.as_ref().unwrap();
+        let shallow_leaf = chain[70].right.as_ref().unwrap();
+
+        let ancestor = closest_common_ancestor_by_depth(&chain[0], deep_leaf, shallow_leaf);
+
+        assert!(ancestor.is_some());
+        assert!(Rc::ptr_eq(&ancestor.unwrap(), &chain[70]));
+
+        let ancestor = closest_common_ancestor_by_depth(&chain[0], &chain[100], &chain[99]);
+
+        assert!(ancestor.is_some());
+        assert!(Rc::ptr_eq(&ancestor.unwrap(), &chain[99]));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "overflow"))]
+    fn index_method_fails_on_skewed_graph() {
+        let chain = skewed_graph(100);
+        let deep_leaf = chain[99].right.as_ref().unwrap();
+        let shallow_leaf = chain[70].right.as_ref().unwrap();
+
+        // With overflow checks, doubling the index panics. Without them, the
+        // index of each leaf deeper than 64 levels wraps around to 1, the
+        // index of the root.
+        let ancestor = closest_common_ancestor(&chain[0], deep_leaf, shallow_leaf);
+
+        assert!(Rc::ptr_eq(&ancestor.unwrap(), &chain[0]));
+    }
+
+    #[test]
+    fn by_depth_solves_very_deep_skewed_graph() {
+        let chain = skewed_graph(100_000);
+        let deep_leaf = chain[99_999].right.as_ref().unwrap();
+        let shallow_leaf = chain[50_000].right.as_ref().unwrap();
+
+        let ancestor = closest_common_ancestor_by_depth(&chain[0], deep_leaf, shallow_leaf);
+
+        assert!(Rc::ptr_eq(&ancestor.unwrap(), &chain[50_000]));
+        assert_eq!(depth_of(&chain[0], deep_leaf), Some(100_000));
+    }
+
+    #[test]
+    fn depth_of_and_path_to() {
+        let g = balanced_graph();
+
+        assert_eq!(depth_of(&g[1], &g[1]), Some(0));
+        assert_eq!(depth_of(&g[1], &g[3]), Some(1));
+        assert_eq!(depth_of(&g[1], &g[13]), Some(3));
+
+        let path = path_to(&g[1], &g[13]).unwrap();
+        assert_eq!(path.len(), 4);
+        assert!(Rc::ptr_eq(&path[0], &g[1]));
+        assert!(Rc::ptr_eq(&path[1], &g[3]));
+        assert!(Rc::ptr_eq(&path[2], &g[6]));
+        assert!(Rc::ptr_eq(&path[3], &g[13]));
+
+        let outsider: Rc<Node> = Default::default();
+        assert_eq!(depth_of(&g[1], &outsider), None);
+    }
+
+    ///         a
+    ///        / \
+    ///       b   e
+    ///      / \   \
+    ///     c   d   f
+    fn labeled_tree() -> Rc<Node<char>> {
+        let c = Rc::new(Node::leaf('c'));
+        let d = Rc::new(Node::leaf('d'));
+        let f = Rc::new(Node::leaf('f'));
+        let b = Rc::new(Node::with_value('b', Some(&c), Some(&d)));
+        let e = Rc::new(Node::with_value('e', None, Some(&f)));
+
+        Rc::new(Node::with_value('a', Some(&b), Some(&e)))
+    }
+
+    #[test]
+    fn pre_order_visits_node_before_children() {
+        let tree = labeled_tree();
+
+        let values: String = tree.pre_order().into_iter().collect();
+
+        assert_eq!(values, "abcdef");
+    }
+
+    #[test]
+    fn post_order_visits_children_before_node() {
+        let tree = labeled_tree();
+
+        let values: String = tree.post_order().into_iter().collect();
+
+        assert_eq!(values, "cdbfea");
+    }
+
+    #[test]
+    fn traversals_of_leaf_are_its_value() {
+        let tree = labeled_tree();
+        let leaf = tree.right.as_ref().unwrap().right.as_ref().unwrap();
+
+        assert_eq!(leaf.pre_order(), vec![&'f']);
+        assert_eq!(leaf.post_order(), vec![&'f']);
+    }
+
+    #[test]
+    fn traversals_skip_missing_child() {
+        let tree = labeled_tree();
+        let e = tree.right.as_ref().unwrap();
+
+        assert_eq!(e.pre_order(), vec![&'e', &'f']);
+        assert_eq!(e.post_order(), vec![&'f', &'e']);
+    }
+
+    #[test]
+    fn traversals_handle_deep_trees() {
+        let chain = skewed_graph(100_000);
+
+        assert_eq!(chain[0].pre_order().len(), 200_001);
+        assert_eq!(chain[0].post_order().len(), 200_001);
+    }
+
+    ///         4
+    ///        / \
+    ///       2   6
+    ///      / \   \
+    ///     1   3   7
+    fn bst() -> Rc<Node<u32>> {
+        let n1 = Rc::new(Node::leaf(1));
+        let n3 = Rc::new(Node::leaf(3));
+        let n7 = Rc::new(Node::leaf(7));
+        let n2 = Rc::new(Node::with_value(2, Some(&n1), Some(&n3)));
+        let n6 = Rc::new(Node::with_value(6, None, Some(&n7)));
+
+        Rc::new(Node::with_value(4, Some(&n2), Some(&n6)))
+    }
+
+    #[test]
+    fn bst_to_sorted_vec_of_small_bst() {
+        let n2 = Rc::new(Node::leaf(2));
+        let n6 = Rc::new(Node::leaf(6));
+        let root = Rc::new(Node::with_value(4, Some(&n2), Some(&n6)));
+
+        assert_eq!(bst_to_sorted_vec(&root), vec![2, 4, 6]);
+        assert_eq!(bst_to_sorted_vec(&bst()), vec![1, 2, 3, 4, 6, 7]);
+    }
+
+    #[test]
+    fn bst_to_sorted_vec_of_single_node() {
+        let root = Rc::new(Node::leaf("a"));
+
+        assert_eq!(bst_to_sorted_vec(&root), vec!["a"]);
+    }
+
+    #[test]
+    fn bst_to_sorted_vec_is_in_order() {
+        let tree = labeled_tree();
+
+        let values: String = bst_to_sorted_vec(&tree).into_iter().collect();
+
+        assert_eq!(values, "cbdaef");
+    }
+
+    #[test]
+    fn is_valid_bst_accepts_bst() {
+        assert!(is_valid_bst(&bst()));
+    }
+
+    #[test]
+    fn is_valid_bst_rejects_violation_at_grandchild() {
+        let n5 = Rc::new(Node::leaf(5));
+        let n2 = Rc::new(Node::with_value(2, None, Some(&n5)));
+        let n6 = Rc::new(Node::leaf(6));
+        let root = Rc::new(Node::with_value(4, Some(&n2), Some(&n6)));
+
+        assert!(!is_valid_bst(&root));
+    }
+
+    #[test]
+    fn is_valid_bst_rejects_duplicates() {
+        let n4 = Rc::new(Node::leaf(4));
+        let root = Rc::new(Node::with_value(4, Some(&n4), None));
+
+        assert!(!is_valid_bst(&root));
+    }
+
+    #[test]
+    fn is_valid_bst_accepts_single_node() {
+        assert!(is_valid_bst(&Rc::new(Node::leaf(1))));
+    }
+
+    /// Copies the tree into threaded nodes.
+    fn threaded<T: Clone>(node: &Node<T>) -> Rc<ThreadedNode<T>> {
+        let left = node.left.as_ref().map(|left| threaded(left));
+        let right = node.right.as_ref().map(|right| threaded(right));
+
+        Rc::new(ThreadedNode::with_value(
+            node.value.clone(),
+            left.as_ref(),
+            right.as_ref(),
+        ))
+    }
+
+    #[test]
+    fn morris_in_order_agrees_with_stack_traversal() {
+        let g = balanced_graph();
+
+        assert_eq!(morris_in_order(&threaded(&g[1])).len(), 15);
+        assert_eq!(morris_in_order(&threaded(&g[1])), bst_to_sorted_vec(&g[1]));
+        assert_eq!(morris_in_order(&threaded(&bst())), vec![1, 2, 3, 4, 6, 7]);
+
+        let values: String = morris_in_order(&threaded(&labeled_tree()))
+            .into_iter()
+            .collect();
+        assert_eq!(values, "cbdaef");
+    }
+
+    #[test]
+    fn morris_in_order_of_balanced_tree_with_values() {
+        // Node `i` has children `2i` and `2i + 1`, like the balanced fixture.
+        let mut nodes: Vec<Rc<ThreadedNode<usize>>> =
+            (0..16).map(|i| Rc::new(ThreadedNode::leaf(i))).collect();
+        for i in (1..8).rev() {
+            nodes[i] = Rc::new(ThreadedNode::with_value(
+                i,
+                Some(&nodes[2 * i]),
+                Some(&nodes[2 * i + 1]),
+            ));
+        }
+
+        assert_eq!(
+            morris_in_order(&nodes[1]),
+            vec![8, 4, 9, 2, 10, 5, 11, 1, 12, 6, 13, 3, 14, 7, 15]
+        );
+    }
+
+    #[test]
+    fn morris_in_order_removes_threads() {
+        let root = threaded(&bst());
+
+        assert_eq!(morris_in_order(&root), morris_in_order(&root));
+        // A thread left behind would hold a reference to the root.
+        assert_eq!(Rc::strong_count(&root), 1);
+        assert!(root.right.borrow().as_ref().unwrap().left.is_none());
+    }
+
+    #[test]
+    fn morris_in_order_handles_deep_trees() {
+        // Left leaning chain, in which every node also has a right leaf.
+        let mut chain = vec![Rc::new(ThreadedNode::leaf(0))];
+        for i in 1..=100_000 {
+            let right = Rc::new(ThreadedNode::leaf(i));
+            let node = ThreadedNode::with_value(i, chain.last(), Some(&right));
+            chain.push(right);
+            chain.push(Rc::new(node));
+        }
+        // The vector keeps every node alive and drops the root first, so that
+        // dropping the chain doesn't recurse through it.
+        chain.reverse();
+
+        assert_eq!(morris_in_order(&chain[0]).len(), 200_001);
+    }
+
+    #[test]
+    fn nodes_at_depth_of_balanced_graph() {
+        let g = balanced_graph();
+
+        assert_eq!(nodes_at_depth(&g[1], 0), 1);
+        assert_eq!(nodes_at_depth(&g[1], 1), 2);
+        assert_eq!(nodes_at_depth(&g[1], 2), 4);
+        assert_eq!(nodes_at_depth(&g[1], 3), 8);
+        assert_eq!(nodes_at_depth(&g[1], 4), 0);
+    }
+
+    #[test]
+    fn nodes_at_depth_of_unbalanced_trees() {
+        assert_eq!(nodes_at_depth(&bst(), 2), 3);
+        assert_eq!(nodes_at_depth(&labeled_tree(), 2), 3);
+
+        let chain = skewed_graph(10);
+        assert_eq!(nodes_at_depth(&chain[0], 10), 2);
+        assert_eq!(nodes_at_depth(&chain[0], 0), 1);
+    }
+
+    #[test]
+    fn max_width_of_balanced_graph_is_its_bottom_level() {
+        let g = balanced_graph();
+
+        assert_eq!(max_width(&g[1]), 8);
+        assert_eq!(max_width(&g[2]), 4);
+        assert_eq!(max_width(&g[15]), 1);
+    }
+
+    #[test]
+    fn max_width_of_chain_is_one() {
+        let mut chain = Rc::new(Node::leaf(0));
+        for value in 1..100 {
+            chain = Rc::new(Node::with_value(value, Some(&chain), None));
+        }
+
+        assert_eq!(max_width(&chain), 1);
+        assert_eq!(max_width(&bst()), 3);
+    }
+
+    #[test]
+    fn kth_smallest_bst_counts_from_one() {
+        let tree = bst();
+
+        assert_eq!(kth_smallest_bst(&tree, 1), Some(1));
+        assert_eq!(kth_smallest_bst(&tree, 4), Some(4));
+        assert_eq!(kth_smallest_bst(&tree, 5), Some(6));
+        assert_eq!(kth_smallest_bst(&tree, 6), Some(7));
+    }
+
+    #[test]
+    fn kth_smallest_bst_out_of_range() {
+        let tree = bst();
+
+        assert_eq!(kth_smallest_bst(&tree, 0), None);
+        assert_eq!(kth_smallest_bst(&tree, 7), None);
+    }
+
+    #[test]
+    fn flattened_tree_is_right_chain_in_pre_order() {
+        let tree = labeled_tree();
+
+        let chain = flatten_to_right_list(&tree);
+
+        let mut values = String::new();
+        let mut node = Some(&chain);
+        while let Some(current) = node {
+            assert!(current.left.is_none());
+            values.push(*current.value());
+            node = current.right.as_ref();
+        }
+        assert_eq!(values, "abcdef");
+    }
+
+    #[test]
+    fn flattening_keeps_original_tree() {
+        let tree = bst();
+
+        let chain = flatten_to_right_list(&tree);
+
+        assert_eq!(max_width(&chain), 1);
+        assert_eq!(chain.pre_order(), tree.pre_order());
+        assert_eq!(bst_to_sorted_vec(&tree), vec![1, 2, 3, 4, 6, 7]);
+        assert_eq!(
+            flatten_to_right_list(&Rc::new(Node::leaf(1))).pre_order(),
+            vec![&1]
+        );
+    }
+
+    #[test]
+    fn flattening_large_balanced_tree() {
+        // Complete tree of 2^20 - 1 nodes built level by level from leaves.
+        let mut level: Vec<Rc<Node<u32>>> = (0..1 << 19).map(|i| Rc::new(Node::leaf(i))).collect();
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| Rc::new(Node::with_value(0, Some(&pair[0]), Some(&pair[1]))))
+                .collect();
+        }
+        let root = level.pop().unwrap();
+
+        let chain = flatten_to_right_list(&root);
+        assert!(chain.left.is_none());
+
+        drop(root);
+        drop(chain);
+    }
 }
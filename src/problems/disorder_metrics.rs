@@ -0,0 +1,122 @@
+//! # Problem
+//! Given an array **A**, count how many swaps of neighbouring elements it
+//! takes to sort it. This is exactly the number of swaps [bubble sort]
+//! performs, so it measures how unfriendly **A** is to bubble sort and to the
+//! other sorts which only move elements by one position at a time.
+//!
+//! ## Example
+//! Given `A = [3, 1, 2]`, output **`2`**: `[3, 1, 2] -> [1, 3, 2] -> [1, 2, 3]`.
+//!
+//! [bubble sort]: ../../algorithms_data_structures_programs/a_003_bubble_sort/index.html
+
+/// Solves the problem in space O(N) and time O(N log N).
+///
+/// Each swap of neighbours which are out of order fixes exactly one inversion,
+/// i.e. a pair of indices `i < j` such that `A[i] > A[j]`. The number of swaps
+/// is therefore the number of inversions. They are counted by merge sort on a
+/// copy of the array: whenever an element of the right half is merged before
+/// the remaining elements of the left half, it forms an inversion with each of
+/// them. Equal elements don't form an inversion, because bubble sort doesn't
+/// swap them.
+pub fn adjacent_swaps_to_sort<T>(array: &[T]) -> u64
+where
+    T: PartialOrd + Clone,
+{
+    let mut copy = array.to_vec();
+
+    sort_and_count(&mut copy)
+}
+
+fn sort_and_count<T>(array: &mut [T]) -> u64
+where
+    T: PartialOrd + Clone,
+{
+    if array.len() < 2 {
+        return 0;
+    }
+
+    let median = array.len() / 2;
+    let mut swaps = sort_and_count(&mut array[..median]) + sort_and_count(&mut array[median..]);
+
+    let left = array[..median].to_vec();
+    let right = array[median..].to_vec();
+    let (mut l, mut r) = (0, 0);
+    for slot in array.iter_mut() {
+        if r == right.len() || (l < left.len() && left[l] <= right[r]) {
+            *slot = left[l].clone();
+            l += 1;
+        } else {
+            *slot = right[r].clone();
+            r += 1;
+            swaps += (left.len() - l) as u64;
+        }
+    }
+
+    swaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    fn brute_force<T: PartialOrd>(array: &[T]) -> u64 {
+        let mut inversions = 0;
+        for i in 0..array.len() {
+            for j in i + 1..array.len() {
+                if array[i] > array[j] {
+                    inversions += 1;
+                }
+            }
+        }
+
+        inversions
+    }
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert_eq!(adjacent_swaps_to_sort(&array), 0);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(adjacent_swaps_to_sort(&[3, 1, 2]), 2);
+    }
+
+    #[test]
+    fn sorted_array_needs_no_swaps() {
+        assert_eq!(adjacent_swaps_to_sort(&[1, 2, 2, 3, 5]), 0);
+        assert_eq!(adjacent_swaps_to_sort(&[7, 7, 7]), 0);
+    }
+
+    #[test]
+    fn reversed_array_needs_most_swaps() {
+        let array: Vec<u32> = (0..100).rev().collect();
+
+        assert_eq!(adjacent_swaps_to_sort(&array), 100 * 99 / 2);
+    }
+
+    #[test]
+    fn it_counts_inversions() {
+        for array in &[
+            vec![2, 4, 1, 3, 5],
+            vec![1, 20, 6, 4, 5],
+            vec![5, 5, 1, 1],
+            vec![2, 1, 2, 1, 2],
+        ] {
+            assert_eq!(adjacent_swaps_to_sort(array), brute_force(array));
+        }
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let mut rng = SeededRng::new(seed);
+            let array: Vec<u32> = (0..rng.below(60)).map(|_| rng.below(10) as u32).collect();
+
+            assert_eq!(adjacent_swaps_to_sort(&array), brute_force(&array));
+        }
+    }
+}
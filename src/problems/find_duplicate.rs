@@ -0,0 +1,89 @@
+//! # Problem
+//! Given an array **A** of **N + 1** integers, each of which is in range
+//! `1..=N`, find a value which occurs more than once. By the pigeonhole
+//! principle, there always is one. Don't mutate **A** and use only constant
+//! extra space.
+//!
+//! ## Example
+//! Given `A = [1, 3, 4, 2, 2]`, output **`2`**.
+
+/// Solves the problem in space O(1) and time O(N) with [Floyd's cycle
+/// detection][floyd].
+///
+/// Treat each value as a pointer to the next index. Index `0` is pointed to by
+/// no value, therefore it's the start of a path. The path must eventually
+/// enter a cycle, because there are finitely many indices. The duplicate value
+/// is the index where the path enters the cycle, because it's the only index
+/// pointed to from two places.
+///
+/// ```text
+/// A = [1, 3, 4, 2, 2]
+///
+/// 0 -> 1 -> 3 -> 2 -> 4
+///                /\   |
+///                 +---+
+/// ```
+///
+/// The tortoise moves one step at a time while the hare moves two. They meet
+/// somewhere in the cycle. Then the tortoise starts from index `0` again and
+/// both move one step at a time. They meet at the entrance to the cycle.
+///
+/// Returns `None` if the array doesn't fulfill the constraints of the problem.
+///
+/// [floyd]: https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare
+pub fn find_duplicate(array: &[usize]) -> Option<usize> {
+    // Each value must point to an index other than the start of the path.
+    if array.len() < 2
+        || array
+            .iter()
+            .any(|&value| value == 0 || value >= array.len())
+    {
+        return None;
+    }
+
+    let mut tortoise = array[0];
+    let mut hare = array[array[0]];
+
+    while tortoise != hare {
+        tortoise = array[tortoise];
+        hare = array[array[hare]];
+    }
+
+    tortoise = 0;
+    while tortoise != hare {
+        tortoise = array[tortoise];
+        hare = array[hare];
+    }
+
+    Some(tortoise)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_solves_example() {
+        let array = [1, 3, 4, 2, 2];
+
+        assert_eq!(find_duplicate(&array), Some(2));
+        assert_eq!(array, [1, 3, 4, 2, 2]);
+    }
+
+    #[test]
+    fn it_finds_duplicate_at_various_positions() {
+        assert_eq!(find_duplicate(&[1, 1]), Some(1));
+        assert_eq!(find_duplicate(&[3, 1, 3, 4, 2]), Some(3));
+        assert_eq!(find_duplicate(&[2, 5, 9, 6, 9, 3, 8, 9, 7, 1]), Some(9));
+        assert_eq!(find_duplicate(&[4, 3, 1, 2, 5, 4]), Some(4));
+        assert_eq!(find_duplicate(&[1, 2, 3, 4, 5, 5]), Some(5));
+    }
+
+    #[test]
+    fn it_returns_none_if_constraints_are_not_met() {
+        assert_eq!(find_duplicate(&[]), None);
+        assert_eq!(find_duplicate(&[1]), None);
+        assert_eq!(find_duplicate(&[0, 1]), None);
+        assert_eq!(find_duplicate(&[1, 2]), None);
+    }
+}
@@ -0,0 +1,56 @@
+//! # Problem
+//! Given an array **A**, find the index of the first element which occurs in
+//! **A** exactly once.
+//!
+//! ## Example
+//! Given `A = ['s', 'w', 'i', 's', 's']`, output **`1`**.
+
+use super::frequencies::frequencies;
+use std::hash::Hash;
+
+/// Solves the problem in space O(N) and time O(N).
+///
+/// The first pass counts occurrences of each element with [`frequencies`]. The
+/// second pass scans the array from the start and stops at the first element
+/// whose count is one.
+///
+/// [`frequencies`]: ../frequencies/fn.frequencies.html
+pub fn first_unique<T>(array: &[T]) -> Option<usize>
+where
+    T: Eq + Hash + Clone,
+{
+    let counts = frequencies(array);
+
+    array.iter().position(|element| counts[element] == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert_eq!(first_unique(&array), None);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(first_unique(&['s', 'w', 'i', 's', 's']), Some(1));
+    }
+
+    #[test]
+    fn it_finds_unique_element_mid_array() {
+        let chars: Vec<char> = "aabbcddeff".chars().collect();
+
+        assert_eq!(first_unique(&chars), Some(4));
+    }
+
+    #[test]
+    fn it_finds_nothing_when_every_element_repeats() {
+        let chars: Vec<char> = "abcabc".chars().collect();
+
+        assert_eq!(first_unique(&chars), None);
+    }
+}
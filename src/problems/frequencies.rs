@@ -0,0 +1,56 @@
+//! # Problem
+//! Given an array **A**, count how many times each distinct element occurs in
+//! it. Unlike the problems on sorted arrays, equal elements may be scattered
+//! anywhere in **A**.
+//!
+//! ## Example
+//! Given `A = ['b', 'a', 'b', 'c', 'b', 'a']`, output
+//! `{'a': 2, 'b': 3, 'c': 1}`.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Solves the problem in space O(N) and time O(N).
+///
+/// The array is visited once. Each element increments its counter in a hash
+/// map, which is created with a zero count on the first occurrence.
+pub fn frequencies<T>(array: &[T]) -> HashMap<T, usize>
+where
+    T: Eq + Hash + Clone,
+{
+    let mut counts = HashMap::new();
+    for element in array {
+        *counts.entry(element.clone()).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert!(frequencies(&array).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let counts = frequencies(&['b', 'a', 'b', 'c', 'b', 'a']);
+
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&'a'], 2);
+        assert_eq!(counts[&'b'], 3);
+        assert_eq!(counts[&'c'], 1);
+    }
+
+    #[test]
+    fn counts_add_up_to_length() {
+        let array: Vec<u32> = (0..1000).map(|x| x * x % 17).collect();
+
+        assert_eq!(frequencies(&array).values().sum::<usize>(), array.len());
+    }
+}
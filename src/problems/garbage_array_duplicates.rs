@@ -101,6 +101,61 @@ where
     new_len
 }
 
+/// Runs [`garbage_array_duplicates`] and splits the array into the unique head
+/// and the garbage tail. The tail is no longer needed by the head, so it can
+/// be reused as scratch space while the head is still borrowed.
+///
+/// [`garbage_array_duplicates`]: fn.garbage_array_duplicates.html
+pub fn split_unique<T>(array: &mut [T]) -> (&mut [T], &mut [T])
+where
+    T: PartialEq,
+{
+    let new_len = garbage_array_duplicates(array);
+
+    array.split_at_mut(new_len)
+}
+
+/// Like [`garbage_array_duplicates`], but leaves the array untouched and
+/// instead returns the index of the first occurrence of each unique element.
+/// Indexing the array with the output yields the same values that the
+/// mutating version would move to the head.
+///
+/// [`garbage_array_duplicates`]: fn.garbage_array_duplicates.html
+pub fn unique_indices<T>(array: &[T]) -> Vec<usize>
+where
+    T: PartialEq,
+{
+    let mut indices = Vec::new();
+    if array.is_empty() {
+        return indices;
+    }
+
+    indices.push(0);
+    for index in 1..array.len() {
+        if array[index] != array[index - 1] {
+            indices.push(index);
+        }
+    }
+
+    indices
+}
+
+/// Counts the unique elements of a sorted array, i.e. returns what
+/// [`garbage_array_duplicates`] would, but without mutating the array. Each
+/// element which differs from its predecessor starts a new unique value.
+///
+/// [`garbage_array_duplicates`]: fn.garbage_array_duplicates.html
+pub fn count_distinct<T>(array: &[T]) -> usize
+where
+    T: PartialEq,
+{
+    if array.is_empty() {
+        return 0;
+    }
+
+    1 + array.windows(2).filter(|pair| pair[0] != pair[1]).count()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,4 +225,81 @@ mod tests {
         assert_eq!(array[2], 3);
         assert_eq!(array[3], 4);
     }
+
+    #[test]
+    fn it_splits_unique_head_from_garbage_tail() {
+        let mut array: [u8; 8] = [1, 1, 2, 3, 4, 4, 4, 5];
+        let end = array.as_ptr_range().end;
+
+        let (head, tail) = split_unique(&mut array);
+
+        assert_eq!(head, &[1, 2, 3, 4, 5]);
+        assert_eq!(tail.len(), 3);
+        // The slices are adjacent and cover the whole array.
+        assert_eq!(head.as_ptr_range().end, tail.as_ptr_range().start);
+        assert_eq!(tail.as_ptr_range().end, end);
+    }
+
+    #[test]
+    fn garbage_tail_can_be_overwritten() {
+        let mut array: [u8; 6] = [7, 7, 7, 8, 8, 9];
+
+        let (head, tail) = split_unique(&mut array);
+        for (scratch, unique) in tail.iter_mut().zip(head.iter()) {
+            *scratch = unique * 2;
+        }
+
+        assert_eq!(array, [7, 8, 9, 14, 16, 18]);
+    }
+
+    #[test]
+    fn split_unique_handles_empty_array() {
+        let mut array: [u8; 0] = [];
+
+        let (head, tail) = split_unique(&mut array);
+
+        assert!(head.is_empty() && tail.is_empty());
+    }
+
+    #[test]
+    fn it_finds_indices_of_first_occurrences() {
+        let array = [1, 1, 2, 3, 3];
+
+        assert_eq!(unique_indices(&array), vec![0, 2, 3]);
+        assert_eq!(array, [1, 1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn it_finds_no_indices_in_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert!(unique_indices(&array).is_empty());
+    }
+
+    #[test]
+    fn unique_indices_agree_with_mutating_version() {
+        let array = [1, 2, 2, 4, 6, 6, 6, 8];
+        let mut copy = array;
+        let new_len = garbage_array_duplicates(&mut copy);
+
+        let values: Vec<_> = unique_indices(&array).iter().map(|&i| array[i]).collect();
+        assert_eq!(values, &copy[..new_len]);
+    }
+
+    #[test]
+    fn it_counts_distinct_elements() {
+        let array = [1, 1, 2, 3, 3, 3, 4];
+
+        assert_eq!(count_distinct(&array), 4);
+        assert_eq!(array, [1, 1, 2, 3, 3, 3, 4]);
+    }
+
+    #[test]
+    fn it_counts_distinct_elements_of_short_arrays() {
+        let empty: [u8; 0] = [];
+
+        assert_eq!(count_distinct(&empty), 0);
+        assert_eq!(count_distinct(&[8]), 1);
+        assert_eq!(count_distinct(&[8, 8, 8]), 1);
+    }
 }
@@ -0,0 +1,111 @@
+//! # Problem
+//! Given two arrays **A** and **B**, decide whether **B** is a cyclic rotation
+//! of **A**. That is whether **B** can be obtained by repeatedly moving the
+//! first element of **A** to its end.
+//!
+//! Every rotation of **A** is a contiguous part of **A** concatenated with
+//! itself. The problem therefore reduces to searching for **B** in **AA**.
+//!
+//! ## Example
+//! Given `A = [1, 2, 3, 4]` and `B = [3, 4, 1, 2]`, output `true`. Given
+//! `B = [1, 2, 4, 3]`, output `false`.
+
+/// Solves the problem in space O(N) and time O(N).
+///
+/// The concatenation **AA** is never built, its elements are read from **A**
+/// modulo its length. **B** is searched for with the Knuth-Morris-Pratt
+/// algorithm, which only needs to compare elements for equality and never
+/// steps back in the searched text.
+pub fn is_rotation<T>(a: &[T], b: &[T]) -> bool
+where
+    T: PartialEq,
+{
+    if a.len() != b.len() {
+        return false;
+    }
+    if a.is_empty() {
+        return true;
+    }
+
+    let n = a.len();
+
+    // For each prefix of B the length of its longest proper prefix which is
+    // also its suffix. On a mismatch the search continues from there.
+    let mut fallback = vec![0; n];
+    let mut matched = 0;
+    for index in 1..n {
+        while matched > 0 && b[index] != b[matched] {
+            matched = fallback[matched - 1];
+        }
+        if b[index] == b[matched] {
+            matched += 1;
+        }
+        fallback[index] = matched;
+    }
+
+    // The last element of AA is never needed, as the rotation starting on it
+    // is the same as the one starting on index 0.
+    let mut matched = 0;
+    for index in 0..(2 * n - 1) {
+        let element = &a[index % n];
+        while matched > 0 && *element != b[matched] {
+            matched = fallback[matched - 1];
+        }
+        if *element == b[matched] {
+            matched += 1;
+        }
+        if matched == n {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_arrays() {
+        let empty: [u8; 0] = [];
+
+        assert!(is_rotation(&empty, &empty));
+        assert!(!is_rotation(&empty, &[1]));
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert!(is_rotation(&[1, 2, 3, 4], &[3, 4, 1, 2]));
+        assert!(!is_rotation(&[1, 2, 3, 4], &[1, 2, 4, 3]));
+    }
+
+    #[test]
+    fn arrays_of_different_lengths_are_not_rotations() {
+        assert!(!is_rotation(&[1, 2, 3], &[1, 2, 3, 1]));
+    }
+
+    #[test]
+    fn array_is_rotation_of_itself() {
+        assert!(is_rotation(&[5, 6, 7], &[5, 6, 7]));
+    }
+
+    #[test]
+    fn it_handles_repeated_elements() {
+        assert!(is_rotation(&[1, 1, 2, 1, 1, 1], &[1, 1, 1, 1, 1, 2]));
+        assert!(!is_rotation(&[1, 1, 2, 1, 2, 1], &[1, 1, 2, 1, 1, 2]));
+        assert!(is_rotation(&[2, 2, 2], &[2, 2, 2]));
+    }
+
+    #[test]
+    fn it_agrees_with_all_rotations() {
+        let array = [3, 1, 4, 1, 5, 9, 2, 6];
+
+        for shift in 0..array.len() {
+            let mut rotated = array.to_vec();
+            rotated.rotate_left(shift);
+
+            assert!(is_rotation(&array, &rotated));
+        }
+    }
+}
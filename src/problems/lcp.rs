@@ -0,0 +1,61 @@
+//! # Problem
+//! Given an array of strings **S**, find the longest prefix which all of them
+//! share.
+//!
+//! ## Example
+//! Given `S = ["flower", "flow", "flight"]`, output **`"fl"`**.
+
+use crate::algorithms_data_structures_programs::a_005_shell_sort::shell_sort;
+
+/// Solves the problem in space O(N) and in time of the sort.
+///
+/// After sorting a copy of the array, the first and the last string differ
+/// the most. Every string in between is bound by them in lexicographic order,
+/// so it shares at least their common prefix. It's enough to compare the two
+/// of them character by character.
+pub fn longest_common_prefix(strings: &[&str]) -> String {
+    let mut sorted = strings.to_vec();
+    shell_sort(&mut sorted);
+
+    match (sorted.first(), sorted.last()) {
+        (Some(first), Some(last)) => first
+            .chars()
+            .zip(last.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect(),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        assert_eq!(longest_common_prefix(&[]), "");
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(longest_common_prefix(&["flower", "flow", "flight"]), "fl");
+    }
+
+    #[test]
+    fn it_finds_no_common_prefix() {
+        assert_eq!(longest_common_prefix(&["dog", "racecar", "car"]), "");
+        assert_eq!(longest_common_prefix(&["abc", "", "abd"]), "");
+    }
+
+    #[test]
+    fn single_string_is_its_own_prefix() {
+        assert_eq!(longest_common_prefix(&["alone"]), "alone");
+    }
+
+    #[test]
+    fn whole_string_can_be_prefix() {
+        assert_eq!(longest_common_prefix(&["ab", "abc", "abcd"]), "ab");
+        assert_eq!(longest_common_prefix(&["žluť", "žlutý"]), "žlu");
+    }
+}
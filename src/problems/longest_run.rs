@@ -0,0 +1,74 @@
+//! # Problem
+//! Given an array **A**, find the longest run of consecutive equal elements.
+//! Output the index at which the run starts and its length. If several runs
+//! are equally long, output the first of them.
+//!
+//! If **A** is sorted, all occurrences of an element form a single run and
+//! the longest run is made of the array's mode.
+//!
+//! ## Example
+//! Given `A = [1, 2, 2, 3, 3, 3, 4]`, output **`(3, 3)`**.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// Works the same way as [`mode_sorted`], except that it remembers where the
+/// longest run starts instead of which element it is made of.
+///
+/// [`mode_sorted`]: ../mode/fn.mode_sorted.html
+pub fn longest_equal_run<T>(array: &[T]) -> Option<(usize, usize)>
+where
+    T: PartialEq,
+{
+    if array.is_empty() {
+        return None;
+    }
+
+    let mut longest = (0, 1);
+    let mut run_start = 0;
+
+    for index in 1..array.len() {
+        if array[index] != array[run_start] {
+            run_start = index;
+        }
+
+        let run_len = index - run_start + 1;
+        if run_len > longest.1 {
+            longest = (run_start, run_len);
+        }
+    }
+
+    Some(longest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert_eq!(longest_equal_run(&array), None);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(longest_equal_run(&[1, 2, 2, 3, 3, 3, 4]), Some((3, 3)));
+    }
+
+    #[test]
+    fn it_finds_single_long_run() {
+        assert_eq!(longest_equal_run(&[7, 7, 7, 7, 7]), Some((0, 5)));
+        assert_eq!(longest_equal_run(&[1, 5, 5, 5, 5]), Some((1, 4)));
+    }
+
+    #[test]
+    fn tie_goes_to_first_run() {
+        assert_eq!(longest_equal_run(&[1, 2, 2, 3, 4, 4, 5]), Some((1, 2)));
+    }
+
+    #[test]
+    fn unique_elements_are_runs_of_one() {
+        assert_eq!(longest_equal_run(&[1, 2, 3, 4]), Some((0, 1)));
+    }
+}
@@ -0,0 +1,153 @@
+//! # Problem
+//! Given an array **A** and an index **M** such that both `A[..M]` and
+//! `A[M..]` are sorted, merge the two runs so that the whole of **A** is
+//! sorted. Don't allocate a buffer for the elements, only O(log N) of call
+//! stack, and keep equal elements in their original order: on a tie the
+//! element from the left run goes first.
+//!
+//! ## Example
+//! Given `A = [1, 4, 7, 2, 3, 8]` and `M = 3`, mutate **A** to a state
+//! `[1, 2, 3, 4, 7, 8]`.
+
+use super::reverse_range::reverse_range;
+use crate::algorithms_data_structures_programs::a_001_binary_search::partition_point;
+
+/// Solves the problem in space O(log N) and time O(N log N).
+///
+/// The median of the longer run is taken as a pivot. Binary search finds where
+/// the pivot belongs in the other run. Rotating the block between the pivot
+/// and that point swaps the right part of the left run with the left part of
+/// the right run:
+///
+/// ```text
+/// [ L1 | p L2 | R1 | R2 ]      R1 < p <= R2
+/// [ L1 | R1 | p L2 | R2 ]      after rotation
+/// ```
+///
+/// Every element of `L1 R1` now precedes every element of `p L2 R2`, so the
+/// two sides are merged independently, each consisting of two sorted runs
+/// again. Each split halves the longer run, hence the recursion is O(log N)
+/// deep and the only auxiliary memory is its stack.
+///
+/// Panics if `mid > array.len()`.
+pub fn merge_in_place<T>(array: &mut [T], mid: usize)
+where
+    T: PartialOrd,
+{
+    assert!(mid <= array.len(), "mid out of bounds");
+
+    let left_len = mid;
+    let right_len = array.len() - mid;
+    if left_len == 0 || right_len == 0 {
+        return;
+    }
+    if left_len + right_len == 2 {
+        if array[1] < array[0] {
+            array.swap(0, 1);
+        }
+        return;
+    }
+
+    // Finds the cut in each run. Elements of the right run move in front of
+    // the pivot only if they are strictly smaller, and elements of the left
+    // run stay in front of it only if they are not larger, which keeps ties
+    // in order.
+    let (left_cut, right_cut) = if left_len >= right_len {
+        let left_cut = left_len / 2;
+        let pivot = &array[left_cut];
+        let right = &array[mid..];
+        let right_cut = mid + partition_point(right_len, |index| right[index] >= *pivot);
+        (left_cut, right_cut)
+    } else {
+        let right_cut = mid + right_len / 2;
+        let pivot = &array[right_cut];
+        let left_cut = partition_point(left_len, |index| array[index] > *pivot);
+        (left_cut, right_cut)
+    };
+
+    // Rotates the block by three reversals: `p L2 R1` reversed piecewise is
+    // `rev(p L2) rev(R1)`, and reversing that as a whole gives `R1 p L2`.
+    reverse_range(array, left_cut, mid);
+    reverse_range(array, mid, right_cut);
+    reverse_range(array, left_cut, right_cut);
+    let new_mid = left_cut + (right_cut - mid);
+
+    let (head, tail) = array.split_at_mut(new_mid);
+    merge_in_place(head, left_cut);
+    merge_in_place(tail, mid - left_cut);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_runs() {
+        let mut empty: [u8; 0] = [];
+        merge_in_place(&mut empty, 0);
+
+        let mut array = [1, 2, 3];
+        merge_in_place(&mut array, 0);
+        merge_in_place(&mut array, 3);
+        assert_eq!(array, [1, 2, 3]);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let mut array = [1, 4, 7, 2, 3, 8];
+
+        merge_in_place(&mut array, 3);
+
+        assert_eq!(array, [1, 2, 3, 4, 7, 8]);
+    }
+
+    #[test]
+    fn it_merges_runs_of_different_lengths() {
+        let mut array = [5, 0, 1, 2, 3, 4, 6];
+        merge_in_place(&mut array, 1);
+        assert_eq!(array, [0, 1, 2, 3, 4, 5, 6]);
+
+        let mut array = [0, 2, 4, 6, 8, 10, 12, 1];
+        merge_in_place(&mut array, 7);
+        assert_eq!(array, [0, 1, 2, 4, 6, 8, 10, 12]);
+    }
+
+    #[test]
+    fn left_run_wins_ties() {
+        let keys = [1, 2, 2, 3, 5, 1, 2, 3, 3, 4];
+        let mut array: Vec<_> = keys
+            .iter()
+            .enumerate()
+            .map(|(index, &key)| ByKey(key, index))
+            .collect();
+
+        merge_in_place(&mut array, 5);
+
+        let order: Vec<_> = array.iter().map(|item| item.1).collect();
+        assert_eq!(order, vec![0, 5, 1, 2, 6, 3, 7, 8, 9, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_if_mid_is_out_of_bounds() {
+        merge_in_place(&mut [1, 2], 3);
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        let mut numbers: Vec<u32> = (0..100).map(|n| n % 30).collect();
+        for seed in 0..FUZZY_TEST_ITERATIONS as u64 {
+            shuffle(&mut numbers, seed);
+            let mid = seed as usize % numbers.len();
+            numbers[..mid].sort();
+            numbers[mid..].sort();
+            let mut expected = numbers.clone();
+            expected.sort();
+
+            merge_in_place(&mut numbers, mid);
+
+            assert_eq!(numbers, expected);
+        }
+    }
+}
@@ -1,4 +1,34 @@
 //! Collection of miscellaneous problems.
 
+pub mod anagrams;
+pub mod argsort;
+pub mod canonical_rotation;
 pub mod closest_common_ancestor;
+pub mod disorder_metrics;
+pub mod find_duplicate;
+pub mod first_unique;
+pub mod frequencies;
 pub mod garbage_array_duplicates;
+pub mod is_rotation;
+pub mod lcp;
+pub mod longest_run;
+pub mod merge_in_place;
+pub mod mode;
+pub mod one_reversal;
+pub mod partial_sort;
+pub mod remove_value;
+pub mod reverse_range;
+pub mod rle;
+pub mod running_median;
+pub mod runs;
+pub mod search_matrix;
+pub mod second_largest;
+pub mod set_ops;
+pub mod single_number;
+pub mod sliding_window;
+pub mod sort_bools;
+pub mod subset_sum;
+pub mod three_sum;
+pub mod top_k_frequent;
+pub mod two_sum;
+pub mod unsorted_window;
@@ -0,0 +1,73 @@
+//! # Problem
+//! Given a sorted array **A**, find its most frequent element. If several
+//! elements are equally frequent, output the first of them.
+//!
+//! ## Example
+//! Given `A = [1, 2, 2, 3, 3, 3, 4]`, output **`3`**.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// Since the array is sorted, equal elements form runs. The array is visited
+/// once while measuring the length of the current run. Whenever a run ends, it
+/// is compared with the longest run so far. A later run has to be strictly
+/// longer to replace the first one, hence ties go to the first element.
+pub fn mode_sorted<T>(array: &[T]) -> Option<T>
+where
+    T: PartialEq + Clone,
+{
+    let first = array.first()?;
+
+    let mut mode = first;
+    let mut mode_len = 0;
+    let mut run = first;
+    let mut run_len = 0;
+
+    for element in array {
+        if element == run {
+            run_len += 1;
+        } else {
+            run = element;
+            run_len = 1;
+        }
+
+        if run_len > mode_len {
+            mode = run;
+            mode_len = run_len;
+        }
+    }
+
+    Some(mode.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert_eq!(mode_sorted(&array), None);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(mode_sorted(&[1, 2, 2, 3, 3, 3, 4]), Some(3));
+    }
+
+    #[test]
+    fn it_finds_runs_at_boundaries() {
+        assert_eq!(mode_sorted(&[1, 1, 1, 2, 3, 3]), Some(1));
+        assert_eq!(mode_sorted(&[1, 2, 2, 3, 3, 3]), Some(3));
+    }
+
+    #[test]
+    fn it_breaks_ties_toward_first_element() {
+        assert_eq!(mode_sorted(&[1, 2, 2, 3, 4, 4]), Some(2));
+    }
+
+    #[test]
+    fn it_handles_all_unique_elements() {
+        assert_eq!(mode_sorted(&["a", "b", "c"]), Some("a"));
+    }
+}
@@ -0,0 +1,151 @@
+//! # Problem
+//! Given an array **A**, decide whether reversing a single contiguous range
+//! of **A** makes it sorted. If so, output the range.
+//!
+//! ## Example
+//! Given `A = [1, 2, 6, 5, 4, 3, 7]`, output **`(2, 6)`**: reversing
+//! `[6, 5, 4, 3]` yields `[1, 2, 3, 4, 5, 6, 7]`. Given `A = [3, 1, 2]`,
+//! output **`None`**.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// The range is half-open, the same as [`reverse_range`] takes. An array
+/// which is already sorted outputs the empty range `(0, 0)`.
+///
+/// Every element out of order has to be reversed, so the range spans at least
+/// from the first element which is larger than its right neighbour to the
+/// last element which is smaller than its left neighbour. Elements equal to
+/// either end are taken into the range too. In `[1, 3, 3, 2]`, reversing only
+/// `[3, 2]` would leave the other `3` in front of the `2`. Reversing then
+/// sorts the array exactly if the range is in descending order and its ends
+/// fit between their new neighbours outside of it.
+///
+/// [`reverse_range`]: ../reverse_range/fn.reverse_range.html
+pub fn sortable_by_one_reversal<T>(array: &[T]) -> Option<(usize, usize)>
+where
+    T: PartialOrd,
+{
+    let mut first = match (1..array.len()).find(|&index| array[index] < array[index - 1]) {
+        Some(index) => index - 1,
+        None => return Some((0, 0)),
+    };
+    let mut last = (1..array.len())
+        .rev()
+        .find(|&index| array[index] < array[index - 1])?;
+
+    while first > 0 && array[first - 1] == array[first] {
+        first -= 1;
+    }
+    while last + 1 < array.len() && array[last + 1] == array[last] {
+        last += 1;
+    }
+
+    let is_descending = (first + 1..=last).all(|index| array[index] <= array[index - 1]);
+    // After the reversal, the last element of the range follows the element
+    // before the range, and the first element of the range precedes the
+    // element after the range.
+    let fits_left = first == 0 || array[first - 1] <= array[last];
+    let fits_right = last + 1 == array.len() || array[first] <= array[last + 1];
+
+    if is_descending && fits_left && fits_right {
+        Some((first, last + 1))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problems::reverse_range::reverse_range;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert_eq!(sortable_by_one_reversal(&array), Some((0, 0)));
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(
+            sortable_by_one_reversal(&[1, 2, 6, 5, 4, 3, 7]),
+            Some((2, 6))
+        );
+        assert_eq!(sortable_by_one_reversal(&[3, 1, 2]), None);
+    }
+
+    #[test]
+    fn sorted_array_outputs_empty_range() {
+        assert_eq!(sortable_by_one_reversal(&[1, 2, 2, 3]), Some((0, 0)));
+    }
+
+    #[test]
+    fn it_finds_range_at_ends() {
+        assert_eq!(sortable_by_one_reversal(&[3, 2, 1, 4]), Some((0, 3)));
+        assert_eq!(sortable_by_one_reversal(&[1, 4, 3, 2]), Some((1, 4)));
+        assert_eq!(sortable_by_one_reversal(&[4, 3, 2, 1]), Some((0, 4)));
+    }
+
+    #[test]
+    fn it_takes_equal_elements_at_ends_into_range() {
+        assert_eq!(sortable_by_one_reversal(&[2, 1, 1]), Some((0, 3)));
+        assert_eq!(sortable_by_one_reversal(&[1, 3, 3, 2]), Some((1, 4)));
+        assert_eq!(sortable_by_one_reversal(&[1, 2, 2, 1]), Some((1, 4)));
+        assert_eq!(sortable_by_one_reversal(&[1, 3, 3, 2, 2, 4]), Some((1, 5)));
+        assert_eq!(sortable_by_one_reversal(&[2, 2, 1, 2]), Some((0, 3)));
+        assert_eq!(sortable_by_one_reversal(&[2, 1, 2, 1]), None);
+    }
+
+    #[test]
+    fn it_rejects_unsortable_arrays() {
+        // Two separate dips.
+        assert_eq!(sortable_by_one_reversal(&[2, 1, 3, 5, 4]), None);
+        // The range is descending, but 5 doesn't fit before 4.
+        assert_eq!(sortable_by_one_reversal(&[5, 3, 2, 4]), None);
+        // The range is not descending.
+        assert_eq!(sortable_by_one_reversal(&[1, 5, 3, 4, 2, 6]), None);
+    }
+
+    #[test]
+    fn reversing_output_range_sorts_array() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let mut array: Vec<u32> = (0..20).collect();
+            let mut rng = SeededRng::new(seed);
+            let lo = rng.below(20);
+            let hi = lo + rng.below(21 - lo);
+            reverse_range(&mut array, lo, hi);
+
+            let (lo, hi) = sortable_by_one_reversal(&array).unwrap();
+            reverse_range(&mut array, lo, hi);
+
+            assert!(is_sorted(&array));
+        }
+    }
+
+    #[test]
+    fn it_agrees_with_brute_force_on_duplicates() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let mut rng = SeededRng::new(seed);
+            let array: Vec<u32> = (0..rng.below(8)).map(|_| rng.below(3) as u32).collect();
+
+            let brute_force = (0..=array.len()).any(|lo| {
+                (lo..=array.len()).any(|hi| {
+                    let mut reversed = array.clone();
+                    reverse_range(&mut reversed, lo, hi);
+                    is_sorted(&reversed)
+                })
+            });
+
+            match sortable_by_one_reversal(&array) {
+                Some((lo, hi)) => {
+                    let mut reversed = array.clone();
+                    reverse_range(&mut reversed, lo, hi);
+                    assert!(is_sorted(&reversed), "{:?}", array);
+                }
+                None => assert!(!brute_force, "{:?}", array),
+            }
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! # Problem
+//! Given an array **A** and a number **K**, mutate **A** in such a way that
+//! its first **K** elements are the **K** smallest elements of **A** in
+//! ascending order. The tail is garbage and can contain the remaining elements
+//! in any order. If **K** is larger than the length of **A**, sort all of it.
+//!
+//! ## Example
+//! Given `A = [9, 1, 8, 2, 7, 3]` and `K = 3`, mutate **A** to a state
+//! `[1, 2, 3, _, _, _]`.
+
+/// Solves the problem in space O(1) and expected time O(N + K log K).
+///
+/// First, quickselect moves the **K** smallest elements to the head in any
+/// order. A pivot, the median of the first, the middle and the last element,
+/// splits the array into elements smaller than, equal to and larger than the
+/// pivot. If the **K**-th position falls into the equal elements, the head is
+/// done. Otherwise only the part containing it is split further. Each split
+/// shrinks the part by a constant fraction on average, therefore the
+/// selection takes O(N) on average. Should unlucky pivots keep the parts from
+/// shrinking, the selection falls back to a heap based selection after O(log N)
+/// splits, which bounds the worst case to O(N log N).
+///
+/// Finally, the head is sorted by heapsort in O(K log K).
+pub fn partial_sort<T>(array: &mut [T], k: usize)
+where
+    T: PartialOrd,
+{
+    let k = k.min(array.len());
+    if k == 0 {
+        return;
+    }
+
+    quickselect(array, k);
+
+    // Heapsort of the head. The largest element is always the root.
+    for index in (0..k / 2).rev() {
+        sift_down(&mut array[..k], index);
+    }
+    for end in (1..k).rev() {
+        array.swap(0, end);
+        sift_down(&mut array[..end], 0);
+    }
+}
+
+/// Moves the `k` smallest elements of the array to its head, in any order.
+fn quickselect<T>(array: &mut [T], k: usize)
+where
+    T: PartialOrd,
+{
+    let mut lo = 0;
+    let mut hi = array.len();
+    // Each split is expected to leave at most three quarters of the part.
+    let mut splits_left = 2 * (usize::BITS - array.len().leading_zeros());
+
+    // The head is done once the boundary `k` is on an edge of the part.
+    while lo < k && k < hi {
+        if splits_left == 0 {
+            heap_select(&mut array[lo..hi], k - lo);
+            return;
+        }
+        splits_left -= 1;
+
+        let part = &mut array[lo..hi];
+        let pivot = median_of_three(part);
+        part.swap(0, pivot);
+
+        // Invariant: `part[..lt]` is smaller than the pivot, `part[lt..i]`
+        // equals the pivot, `part[i..gt]` is not visited yet and `part[gt..]`
+        // is larger. The pivot itself is always on index `lt`.
+        let (mut lt, mut i, mut gt) = (0, 1, part.len());
+        while i < gt {
+            if part[i] < part[lt] {
+                part.swap(lt, i);
+                lt += 1;
+                i += 1;
+            } else if part[i] > part[lt] {
+                gt -= 1;
+                part.swap(i, gt);
+            } else {
+                i += 1;
+            }
+        }
+
+        if k < lo + lt {
+            hi = lo + lt;
+        } else if k > lo + gt {
+            lo += gt;
+        } else {
+            return;
+        }
+    }
+}
+
+/// Returns the index of the median of the first, the middle and the last
+/// element of a non-empty array.
+fn median_of_three<T>(array: &[T]) -> usize
+where
+    T: PartialOrd,
+{
+    let (a, b, c) = (0, array.len() / 2, array.len() - 1);
+
+    if (array[a] <= array[b]) == (array[b] <= array[c]) {
+        b
+    } else if (array[b] <= array[a]) == (array[a] <= array[c]) {
+        a
+    } else {
+        c
+    }
+}
+
+/// Moves the `k` smallest elements of the array to its head, in any order, in
+/// time O(N log K).
+///
+/// The head of the array of length `k` is turned into a max-heap. The root of
+/// the heap is therefore the largest of the `k` smallest elements found so
+/// far. Each element of the tail is compared with the root. If it's smaller,
+/// it replaces the root, which then sinks down to its place. After all
+/// elements have been visited, the heap contains the `k` smallest elements.
+fn heap_select<T>(array: &mut [T], k: usize)
+where
+    T: PartialOrd,
+{
+    if k == 0 {
+        return;
+    }
+
+    // Builds the max-heap from the bottom up. Leaves are valid heaps already.
+    for index in (0..k / 2).rev() {
+        sift_down(&mut array[..k], index);
+    }
+
+    for index in k..array.len() {
+        if array[index] < array[0] {
+            array.swap(0, index);
+            sift_down(&mut array[..k], 0);
+        }
+    }
+}
+
+/// Moves the element on given index down the max-heap until both of its
+/// children are smaller or equal.
+fn sift_down<T>(heap: &mut [T], mut index: usize)
+where
+    T: PartialOrd,
+{
+    loop {
+        let left = index * 2 + 1;
+        let right = left + 1;
+        let mut largest = index;
+
+        if left < heap.len() && heap[left] > heap[largest] {
+            largest = left;
+        }
+        if right < heap.len() && heap[right] > heap[largest] {
+            largest = right;
+        }
+
+        if largest == index {
+            return;
+        }
+
+        heap.swap(index, largest);
+        index = largest;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let mut array: [u8; 0] = [];
+
+        partial_sort(&mut array, 3);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let mut array = [9, 1, 8, 2, 7, 3];
+
+        partial_sort(&mut array, 3);
+
+        assert_eq!(array[..3], [1, 2, 3]);
+    }
+
+    #[test]
+    fn it_keeps_all_elements() {
+        let mut array = [9, 1, 8, 2, 7, 3];
+
+        partial_sort(&mut array, 2);
+        array.sort();
+
+        assert_eq!(array, [1, 2, 3, 7, 8, 9]);
+    }
+
+    #[test]
+    fn it_matches_sorted_copy_for_every_k() {
+        let mut sorted: Vec<u32> = (0..30).map(|x| x / 2).collect();
+        shuffle(&mut sorted, 3);
+        let original = sorted.clone();
+        sorted.sort();
+
+        for k in 0..=original.len() + 1 {
+            let mut array = original.clone();
+
+            partial_sort(&mut array, k);
+
+            let k = k.min(array.len());
+            assert_eq!(array[..k], sorted[..k]);
+        }
+    }
+
+    #[test]
+    fn it_handles_many_equal_elements() {
+        let mut array = vec![5u32; 100_000];
+        array[77] = 1;
+
+        partial_sort(&mut array, 3);
+
+        assert_eq!(array[..3], [1, 5, 5]);
+    }
+
+    #[test]
+    fn heap_select_moves_smallest_to_head() {
+        let mut array = [9, 1, 8, 2, 7, 3];
+
+        heap_select(&mut array, 3);
+        array[..3].sort();
+
+        assert_eq!(array[..3], [1, 2, 3]);
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let mut rng = SeededRng::new(seed);
+            let original: Vec<u32> = (0..rng.below(200)).map(|_| rng.below(50) as u32).collect();
+            let k = rng.below(original.len() + 2);
+            let mut sorted = original.clone();
+            sorted.sort();
+
+            let mut array = original.clone();
+            partial_sort(&mut array, k);
+
+            let k = k.min(array.len());
+            assert_eq!(array[..k], sorted[..k]);
+            assert_eq!(multiset_diff(&array, &original), (vec![], vec![]));
+        }
+    }
+}
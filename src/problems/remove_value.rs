@@ -0,0 +1,83 @@
+//! # Problem
+//! Given an array **A** and a value **V**, remove all occurrences of **V**
+//! from **A** while keeping the order of the remaining elements. Output how
+//! many elements were removed.
+//!
+//! ## Example
+//! Given `A = [3, 1, 3, 2, 3]` and `V = 3`, mutate **A** to `[1, 2]` and
+//! output **`3`**.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// Similarly to [`garbage_array_duplicates`], elements which are to be kept
+/// are moved to the head of the array, one after another, as they are
+/// visited. The occurrences of **V** are thereby pushed into the tail, which
+/// is cut off in the end.
+///
+/// [`garbage_array_duplicates`]: ../garbage_array_duplicates/index.html
+pub fn remove_value<T>(array: &mut Vec<T>, value: &T) -> usize
+where
+    T: PartialEq,
+{
+    let mut kept = 0;
+
+    for index in 0..array.len() {
+        if array[index] != *value {
+            array.swap(kept, index);
+            kept += 1;
+        }
+    }
+
+    let removed = array.len() - kept;
+    array.truncate(kept);
+
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let mut array: Vec<u8> = Vec::new();
+
+        assert_eq!(remove_value(&mut array, &1), 0);
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let mut array = vec![3, 1, 3, 2, 3];
+
+        assert_eq!(remove_value(&mut array, &3), 3);
+        assert_eq!(array, vec![1, 2]);
+    }
+
+    #[test]
+    fn it_keeps_array_if_value_is_not_present() {
+        let mut array = vec![1, 2, 3];
+
+        assert_eq!(remove_value(&mut array, &4), 0);
+        assert_eq!(array, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn it_removes_all_elements() {
+        let mut array = vec![5, 5, 5];
+
+        assert_eq!(remove_value(&mut array, &5), 3);
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn it_agrees_with_retain() {
+        let mut array = vec![0, 1, 0, 2, 0, 0, 3, 4, 0];
+        let mut expected = array.clone();
+        expected.retain(|&element| element != 0);
+
+        remove_value(&mut array, &0);
+
+        assert_eq!(array, expected);
+    }
+}
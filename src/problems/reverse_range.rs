@@ -0,0 +1,81 @@
+//! # Problem
+//! Given an array **A** and a range `lo..hi`, reverse the order of elements of
+//! **A** within the range in place. Elements outside of the range stay where
+//! they are.
+//!
+//! ## Example
+//! Given `A = [1, 2, 3, 4, 5, 6]` and range `1..4`, mutate **A** to a state
+//! `[1, 4, 3, 2, 5, 6]`.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// Two trackers start at the ends of the range, swap their elements and move
+/// toward each other until they meet.
+///
+/// Panics unless `lo <= hi <= array.len()`.
+pub fn reverse_range<T>(array: &mut [T], lo: usize, hi: usize) {
+    assert!(lo <= hi && hi <= array.len(), "range out of bounds");
+
+    // Like the upper bound, the right tracker is exclusive: it points one past
+    // the element to swap. That way it never underflows on an empty range.
+    let mut left = lo;
+    let mut right = hi;
+
+    while left + 1 < right {
+        right -= 1;
+        array.swap(left, right);
+        left += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_solves_example() {
+        let mut array = [1, 2, 3, 4, 5, 6];
+
+        reverse_range(&mut array, 1, 4);
+
+        assert_eq!(array, [1, 4, 3, 2, 5, 6]);
+    }
+
+    #[test]
+    fn it_reverses_whole_slice() {
+        let mut odd = [1, 2, 3, 4, 5];
+        let mut even = [1, 2, 3, 4];
+
+        reverse_range(&mut odd, 0, 5);
+        reverse_range(&mut even, 0, 4);
+
+        assert_eq!(odd, [5, 4, 3, 2, 1]);
+        assert_eq!(even, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn it_handles_empty_and_single_element_ranges() {
+        let mut array = [1, 2, 3];
+
+        reverse_range(&mut array, 0, 0);
+        reverse_range(&mut array, 3, 3);
+        reverse_range(&mut array, 1, 2);
+
+        assert_eq!(array, [1, 2, 3]);
+
+        let mut empty: [u8; 0] = [];
+        reverse_range(&mut empty, 0, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_when_range_is_out_of_bounds() {
+        reverse_range(&mut [1, 2, 3], 1, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_when_range_is_inverted() {
+        reverse_range(&mut [1, 2, 3], 2, 1);
+    }
+}
@@ -0,0 +1,78 @@
+//! # Problem
+//! Given an array **A**, output each distinct element of **A** together with
+//! the number of its occurrences, ordered by the elements. This is a histogram
+//! of **A**.
+//!
+//! Once **A** is sorted, equal elements form runs and the problem becomes
+//! run-length encoding of the sorted array.
+//!
+//! ## Example
+//! Given `A = [3, 1, 3, 2, 1, 1]`, output **`[(1, 3), (2, 1), (3, 2)]`**.
+
+use crate::algorithms_data_structures_programs::a_005_shell_sort::shell_sort;
+
+/// Solves the problem in space O(N) and in time of the sort.
+///
+/// A copy of the array is sorted with [`shell_sort`]. Then the copy is visited
+/// once, like in [`garbage_array_duplicates`], and the count of the last
+/// distinct element grows until a different element comes.
+///
+/// [`shell_sort`]: ../../algorithms_data_structures_programs/a_005_shell_sort/fn.shell_sort.html
+/// [`garbage_array_duplicates`]: ../garbage_array_duplicates/index.html
+pub fn run_length_encode<T>(array: &[T]) -> Vec<(T, usize)>
+where
+    T: PartialOrd + Clone,
+{
+    let mut sorted = array.to_vec();
+    shell_sort(&mut sorted);
+
+    let mut encoded: Vec<(T, usize)> = Vec::new();
+    for element in sorted {
+        match encoded.last_mut() {
+            Some((last, count)) if *last == element => *count += 1,
+            _ => encoded.push((element, 1)),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert!(run_length_encode(&array).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(
+            run_length_encode(&[3, 1, 3, 2, 1, 1]),
+            vec![(1, 3), (2, 1), (3, 2)]
+        );
+    }
+
+    #[test]
+    fn it_leaves_input_untouched() {
+        let array = ['b', 'a', 'b'];
+
+        assert_eq!(run_length_encode(&array), vec![('a', 1), ('b', 2)]);
+        assert_eq!(array, ['b', 'a', 'b']);
+    }
+
+    #[test]
+    fn counts_add_up_to_length() {
+        let array = [5, 0, 5, 9, 0, 0, 1, 5, 5, 2];
+
+        let total: usize = run_length_encode(&array)
+            .iter()
+            .map(|(_, count)| count)
+            .sum();
+
+        assert_eq!(total, array.len());
+    }
+}
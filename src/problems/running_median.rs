@@ -0,0 +1,120 @@
+//! # Problem
+//! Numbers arrive one at a time. After each of them, output the median of all
+//! numbers which have arrived so far. If there is an even count of them, the
+//! median is the mean of the two middle ones.
+//!
+//! ## Example
+//! Given a stream `5, 2, 8, 1, 9`, output `5, 3.5, 5, 3.5, 5`.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Solves the problem in time O(log N) per number and space O(N).
+///
+/// Numbers are split into two halves. The lower half is kept in a max-heap and
+/// the upper half in a min-heap. Therefore the middle numbers are on the tops
+/// of the heaps. After each insertion, the halves are rebalanced so that the
+/// lower half has either the same count of numbers as the upper half, or one
+/// more.
+///
+/// ```text
+///   lower (max-heap)       upper (min-heap)
+///  1, 2, [5]              [8], 9
+///         \_____ median
+/// ```
+#[derive(Debug, Default)]
+pub struct RunningMedian {
+    lower: BinaryHeap<i64>,
+    // Standard library heap is a max-heap. Reverse makes it a min-heap.
+    upper: BinaryHeap<Reverse<i64>>,
+}
+
+impl RunningMedian {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn insert(&mut self, value: i64) {
+        match self.lower.peek() {
+            Some(&top) if value > top => self.upper.push(Reverse(value)),
+            _ => self.lower.push(value),
+        }
+
+        if self.lower.len() > self.upper.len() + 1 {
+            let top = self.lower.pop().unwrap();
+            self.upper.push(Reverse(top));
+        } else if self.upper.len() > self.lower.len() {
+            let Reverse(top) = self.upper.pop().unwrap();
+            self.lower.push(top);
+        }
+    }
+
+    /// Returns the median of the numbers inserted so far, or `None` if none
+    /// were.
+    pub fn median(&self) -> Option<f64> {
+        let &lower = self.lower.peek()?;
+
+        if self.lower.len() > self.upper.len() {
+            Some(lower as f64)
+        } else {
+            let &Reverse(upper) = self.upper.peek()?;
+            Some((lower as f64 + upper as f64) / 2.0)
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_has_no_median_when_empty() {
+        let median = RunningMedian::new();
+
+        assert_eq!(median.median(), None);
+        assert!(median.is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let mut median = RunningMedian::new();
+        let mut medians = Vec::new();
+
+        for &value in &[5, 2, 8, 1, 9] {
+            median.insert(value);
+            medians.push(median.median().unwrap());
+        }
+
+        assert_eq!(medians, vec![5.0, 3.5, 5.0, 3.5, 5.0]);
+        assert_eq!(median.len(), 5);
+    }
+
+    #[test]
+    fn it_matches_median_of_sorted_prefix() {
+        let stream: Vec<i64> = (0..100).map(|x| (x * 37) % 101 - 50).collect();
+        let mut median = RunningMedian::new();
+
+        for (index, &value) in stream.iter().enumerate() {
+            median.insert(value);
+
+            let mut prefix = stream[..=index].to_vec();
+            prefix.sort();
+            let middle = prefix.len() / 2;
+            let expected = if prefix.len() % 2 == 1 {
+                prefix[middle] as f64
+            } else {
+                (prefix[middle - 1] + prefix[middle]) as f64 / 2.0
+            };
+
+            assert_eq!(median.median(), Some(expected));
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! # Problem
+//! Given an array **A**, split it into maximal runs of elements in ascending
+//! order. A run is maximal if it can't be extended by a neighbouring element
+//! without breaking the order. The number of runs measures how far **A** is
+//! from being sorted: a sorted array is a single run, while an array sorted in
+//! reverse has as many runs as elements. Natural merge sorts begin with
+//! exactly this analysis.
+//!
+//! ## Example
+//! Given `A = [1, 3, 5, 2, 2, 4, 0]`, output `[(0, 3), (3, 6), (6, 7)]`.
+
+/// Solves the problem in time O(N).
+///
+/// Each run is a half-open range `(start, end)` of indices. A new run starts
+/// wherever an element is smaller than its predecessor. Equal neighbours don't
+/// break a run.
+pub fn run_detect<T>(array: &[T]) -> Vec<(usize, usize)>
+where
+    T: PartialOrd,
+{
+    let mut runs = Vec::new();
+    if array.is_empty() {
+        return runs;
+    }
+
+    let mut start = 0;
+    for index in 1..array.len() {
+        if array[index] < array[index - 1] {
+            runs.push((start, index));
+            start = index;
+        }
+    }
+    runs.push((start, array.len()));
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert!(run_detect(&array).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(
+            run_detect(&[1, 3, 5, 2, 2, 4, 0]),
+            vec![(0, 3), (3, 6), (6, 7)]
+        );
+    }
+
+    #[test]
+    fn sorted_array_is_single_run() {
+        assert_eq!(run_detect(&[1, 2, 2, 3, 4]), vec![(0, 5)]);
+    }
+
+    #[test]
+    fn reversed_array_is_all_singletons() {
+        assert_eq!(
+            run_detect(&[4, 3, 2, 1]),
+            vec![(0, 1), (1, 2), (2, 3), (3, 4)]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! # Problem
+//! Given a matrix **M** whose every row and every column is sorted in
+//! ascending order, find the position of a target value **X**.
+//!
+//! ## Example
+//!
+//! ```text
+//!      1    4    7   11
+//!      2    5    8   12
+//!      3    6    9   16
+//!     10   13   14   17
+//! ```
+//!
+//! Given `X = 9`, output **`(2, 2)`**.
+
+/// Solves the problem in space O(1) and time O(R + C), where R is the number
+/// of rows and C is the number of columns.
+///
+/// The search starts in the top right corner. Everything to the left of the
+/// current element in the same row is smaller, and everything below it in the
+/// same column is larger. Therefore if the current element is larger than the
+/// target, the whole column can be discarded, and if it's smaller, the whole
+/// row can be discarded. Each step discards a row or a column, going down the
+/// matrix like a staircase.
+///
+/// ```text
+///      1    4    7  [11]     11 > 9, move left
+///      2    5    8   12
+///      3    6    9   16
+///     10   13   14   17
+///
+///      1    4   [7]  11       7 < 9, move down
+///      2    5    8   12
+///      3    6    9   16
+///     10   13   14   17
+///
+///      1    4    7   11
+///      2    5   [8]  12       8 < 9, move down
+///      3    6    9   16
+///     10   13   14   17
+///
+///      1    4    7   11
+///      2    5    8   12
+///      3    6   [9]  16       found
+///     10   13   14   17
+/// ```
+///
+/// All rows are expected to be of the same length.
+pub fn search_sorted_matrix(matrix: &[Vec<i64>], target: i64) -> Option<(usize, usize)> {
+    let columns = matrix.first()?.len();
+    if columns == 0 {
+        return None;
+    }
+
+    let mut row = 0;
+    let mut column = columns - 1;
+
+    while row < matrix.len() {
+        let element = matrix[row][column];
+
+        if element == target {
+            return Some((row, column));
+        } else if element > target {
+            // The whole column below is even larger.
+            if column == 0 {
+                return None;
+            }
+            column -= 1;
+        } else {
+            // The whole row to the left is even smaller.
+            row += 1;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix() -> Vec<Vec<i64>> {
+        vec![
+            vec![1, 4, 7, 11],
+            vec![2, 5, 8, 12],
+            vec![3, 6, 9, 16],
+            vec![10, 13, 14, 17],
+        ]
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(search_sorted_matrix(&matrix(), 9), Some((2, 2)));
+    }
+
+    #[test]
+    fn it_finds_every_element() {
+        let matrix = matrix();
+
+        for (row, values) in matrix.iter().enumerate() {
+            for (column, &value) in values.iter().enumerate() {
+                assert_eq!(search_sorted_matrix(&matrix, value), Some((row, column)));
+            }
+        }
+    }
+
+    #[test]
+    fn it_returns_none_if_target_is_not_present() {
+        let matrix = matrix();
+
+        assert_eq!(search_sorted_matrix(&matrix, 0), None);
+        assert_eq!(search_sorted_matrix(&matrix, 15), None);
+        assert_eq!(search_sorted_matrix(&matrix, 20), None);
+    }
+
+    #[test]
+    fn it_handles_empty_matrix() {
+        assert_eq!(search_sorted_matrix(&[], 1), None);
+        assert_eq!(search_sorted_matrix(&[vec![]], 1), None);
+    }
+}
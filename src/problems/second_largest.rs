@@ -0,0 +1,73 @@
+//! # Problem
+//! Given an array **A**, find its second largest distinct value: the largest
+//! value which is smaller than the maximum of **A**.
+//!
+//! ## Example
+//! Given `A = [5, 1, 5, 3]`, output **`3`**.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// The array is visited once while keeping the largest and the second
+/// largest value so far. A new maximum demotes the old one to second place.
+/// A value equal to the maximum is a duplicate and changes nothing. Outputs
+/// `None` unless **A** has at least two distinct values.
+pub fn second_largest<T>(array: &[T]) -> Option<T>
+where
+    T: PartialOrd + Clone,
+{
+    let mut largest: Option<&T> = None;
+    let mut second: Option<&T> = None;
+
+    for element in array {
+        match largest {
+            Some(max) if element == max => (),
+            Some(max) if element < max => {
+                if second.is_none_or(|second| element > second) {
+                    second = Some(element);
+                }
+            }
+            _ => {
+                second = largest;
+                largest = Some(element);
+            }
+        }
+    }
+
+    second.cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert_eq!(second_largest(&array), None);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(second_largest(&[5, 1, 5, 3]), Some(3));
+    }
+
+    #[test]
+    fn duplicates_of_maximum_are_skipped() {
+        assert_eq!(second_largest(&[5, 5, 3]), Some(3));
+        assert_eq!(second_largest(&[3, 5, 5]), Some(3));
+        assert_eq!(second_largest(&[2, 9, 4, 9, 4]), Some(4));
+    }
+
+    #[test]
+    fn it_handles_two_distinct_values() {
+        assert_eq!(second_largest(&[1, 2]), Some(1));
+        assert_eq!(second_largest(&[2, 1, 2, 1]), Some(1));
+    }
+
+    #[test]
+    fn single_distinct_value_has_no_second() {
+        assert_eq!(second_largest(&[7]), None);
+        assert_eq!(second_largest(&[7, 7, 7]), None);
+    }
+}
@@ -0,0 +1,138 @@
+//! # Problem
+//! Given two sorted arrays **A** and **B** which represent sets, compute
+//! their difference: elements which are in **A** but not in **B**, and their
+//! union: elements which are in **A** or in **B**.
+//!
+//! Both arrays are walked at once with one index each, like in the merge step
+//! of merge sort. The index pointing at the smaller element is the one which
+//! advances, because the smaller element can't be in the other array anymore.
+//!
+//! ## Example
+//! Given `A = [1, 2, 4, 5, 7]` and `B = [2, 3, 5, 6]`, output `[1, 4, 7]` for
+//! the difference and `[1, 2, 3, 4, 5, 6, 7]` for the union.
+
+/// Solves the problem in time O(N + M).
+///
+/// The arrays are treated as sets, so the output is sorted and has no
+/// duplicates. An element which is present in **B** is left out however many
+/// times it occurs in **A**, and an element which is not present in **B** is
+/// output once even if **A** contains it repeatedly.
+pub fn difference_sorted<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: PartialOrd + Clone,
+{
+    let mut difference: Vec<T> = Vec::new();
+    let mut b_index = 0;
+
+    for element in a {
+        while b_index < b.len() && b[b_index] < *element {
+            b_index += 1;
+        }
+
+        let is_in_b = b_index < b.len() && b[b_index] == *element;
+        let is_repeated = difference.last() == Some(element);
+        if !is_in_b && !is_repeated {
+            difference.push(element.clone());
+        }
+    }
+
+    difference
+}
+
+/// Solves the union in time O(N + M).
+///
+/// Like with [`difference_sorted`], the output is sorted and has no
+/// duplicates, neither those repeated within one array nor those shared by
+/// both arrays.
+///
+/// [`difference_sorted`]: fn.difference_sorted.html
+pub fn union_sorted<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: PartialOrd + Clone,
+{
+    let mut union: Vec<T> = Vec::with_capacity(a.len() + b.len());
+    let mut a_index = 0;
+    let mut b_index = 0;
+
+    while a_index < a.len() || b_index < b.len() {
+        // Takes the smaller of the two current elements, or the remaining one
+        // if the other array has been exhausted.
+        let element = if b_index == b.len() || (a_index < a.len() && a[a_index] <= b[b_index]) {
+            a_index += 1;
+            &a[a_index - 1]
+        } else {
+            b_index += 1;
+            &b[b_index - 1]
+        };
+
+        if union.last() != Some(element) {
+            union.push(element.clone());
+        }
+    }
+
+    union
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_arrays() {
+        let empty: [u8; 0] = [];
+
+        assert!(difference_sorted(&empty, &empty).is_empty());
+        assert!(difference_sorted(&empty, &[1, 2]).is_empty());
+        assert_eq!(difference_sorted(&[1, 2], &empty), vec![1, 2]);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(
+            difference_sorted(&[1, 2, 4, 5, 7], &[2, 3, 5, 6]),
+            vec![1, 4, 7]
+        );
+    }
+
+    #[test]
+    fn disjoint_difference_is_first_array() {
+        assert_eq!(difference_sorted(&[1, 3, 5], &[0, 2, 4, 6]), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn overlapping_difference_is_empty() {
+        assert!(difference_sorted(&[1, 2, 3], &[1, 2, 3]).is_empty());
+        assert!(difference_sorted(&[2, 3], &[1, 2, 3, 4]).is_empty());
+    }
+
+    #[test]
+    fn it_removes_duplicates() {
+        assert_eq!(
+            difference_sorted(&[1, 1, 2, 2, 3, 3, 3], &[2, 2]),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn it_unites_overlapping_arrays() {
+        assert_eq!(union_sorted(&[1, 2, 3], &[2, 3, 4]), vec![1, 2, 3, 4]);
+        assert_eq!(
+            union_sorted(&[1, 2, 4, 5, 7], &[2, 3, 5, 6]),
+            vec![1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn union_with_empty_array_is_identity() {
+        let empty: [u8; 0] = [];
+
+        assert!(union_sorted(&empty, &empty).is_empty());
+        assert_eq!(union_sorted(&[1, 2, 3], &empty), vec![1, 2, 3]);
+        assert_eq!(union_sorted(&empty, &[1, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn union_removes_duplicates() {
+        assert_eq!(union_sorted(&[1, 1, 3], &[1, 3, 3, 5]), vec![1, 3, 5]);
+    }
+}
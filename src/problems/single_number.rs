@@ -0,0 +1,66 @@
+//! # Problem
+//! Given an array **A** in which every value occurs exactly twice except for
+//! a single one, find the value which occurs only once.
+//!
+//! ## Example
+//! Given `A = [4, 1, 2, 1, 2]`, output **`4`**.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// XOR of a value with itself is zero and XOR with zero is the value itself.
+/// XOR is also commutative and associative, so the order of the elements
+/// doesn't matter. XOR of the whole array therefore cancels out every pair
+/// and leaves only the single value.
+///
+/// The output is only meaningful if the array meets the precondition. It isn't
+/// checked, because that would need more than O(1) space. For example, in an
+/// array with three equal values, two of them cancel out and the third one
+/// mixes into the output. Empty array has no single value, hence `None`.
+pub fn single_number(array: &[u64]) -> Option<u64> {
+    if array.is_empty() {
+        return None;
+    }
+
+    Some(array.iter().fold(0, |acc, value| acc ^ value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        assert_eq!(single_number(&[]), None);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(single_number(&[4, 1, 2, 1, 2]), Some(4));
+    }
+
+    #[test]
+    fn it_finds_single_value_at_any_position() {
+        assert_eq!(single_number(&[7]), Some(7));
+        assert_eq!(single_number(&[3, 3, 9, 5, 5]), Some(9));
+        assert_eq!(single_number(&[3, 5, 3, 5, 0]), Some(0));
+        assert_eq!(single_number(&[u64::MAX, 1, 1]), Some(u64::MAX));
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let mut rng = SeededRng::new(seed);
+            let single = rng.next_u64();
+            let mut array = vec![single];
+            for _ in 0..rng.below(50) {
+                let value = rng.next_u64();
+                array.push(value);
+                array.push(value);
+            }
+            shuffle(&mut array, seed);
+
+            assert_eq!(single_number(&array), Some(single));
+        }
+    }
+}
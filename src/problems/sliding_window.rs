@@ -0,0 +1,104 @@
+//! # Problem
+//! Given an array **A** and a window size **W**, output the maximum of each
+//! contiguous window of **W** elements of **A**, from left to right.
+//!
+//! ## Example
+//! Given `A = [1, 3, -1, -3, 5, 3, 6, 7]` and `W = 3`, output
+//! **`[3, 3, 5, 5, 6, 7]`**.
+
+use std::collections::VecDeque;
+
+/// Solves the problem in space O(W) and time O(N).
+///
+/// A deque keeps indices of the elements of the current window which can
+/// still become its maximum, with their values in descending order. An
+/// element is dropped from the back when a larger one enters the window, as
+/// the larger one outlives it, and from the front when it leaves the window.
+/// The front of the deque is then always the maximum. Each index enters and
+/// leaves the deque once.
+///
+/// There is no window if **W** is zero or larger than the length of **A**,
+/// and the output is empty.
+pub fn sliding_window_max(array: &[i64], window: usize) -> Vec<i64> {
+    if window == 0 || window > array.len() {
+        return Vec::new();
+    }
+
+    let mut maxima = Vec::with_capacity(array.len() - window + 1);
+    let mut candidates: VecDeque<usize> = VecDeque::with_capacity(window);
+
+    for (index, &element) in array.iter().enumerate() {
+        while candidates
+            .back()
+            .is_some_and(|&back| array[back] <= element)
+        {
+            candidates.pop_back();
+        }
+        candidates.push_back(index);
+
+        if candidates
+            .front()
+            .is_some_and(|&front| front + window <= index)
+        {
+            candidates.pop_front();
+        }
+
+        if index + 1 >= window {
+            if let Some(&front) = candidates.front() {
+                maxima.push(array[front]);
+            }
+        }
+    }
+
+    maxima
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        assert!(sliding_window_max(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(
+            sliding_window_max(&[1, 3, -1, -3, 5, 3, 6, 7], 3),
+            vec![3, 3, 5, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn it_handles_windows_without_elements() {
+        assert!(sliding_window_max(&[1, 2, 3], 0).is_empty());
+        assert!(sliding_window_max(&[1, 2, 3], 4).is_empty());
+    }
+
+    #[test]
+    fn it_handles_extreme_window_sizes() {
+        let array = [4, -2, 7, 7, 0];
+
+        assert_eq!(sliding_window_max(&array, 1), array.to_vec());
+        assert_eq!(sliding_window_max(&array, 5), vec![7]);
+    }
+
+    #[test]
+    fn it_agrees_with_brute_force() {
+        let mut array: Vec<i64> = (0..40).map(|x| (x * 17) % 23 - 11).collect();
+
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            shuffle(&mut array, seed);
+            let window = 1 + seed as usize % array.len();
+
+            let expected: Vec<i64> = array
+                .windows(window)
+                .map(|w| *w.iter().max().unwrap())
+                .collect();
+
+            assert_eq!(sliding_window_max(&array, window), expected);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! # Problem
+//! Given an array of booleans **A**, sort it so that all `false` values come
+//! before all `true` values.
+//!
+//! ## Example
+//! Given `A = [true, false, true, false, false]`, mutate **A** to a state
+//! `[false, false, false, true, true]`.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// This is counting sort with only two possible values. The first pass counts
+/// the `false` values, the second one writes that many `false` values followed
+/// by `true` values. Equal booleans are indistinguishable, so the sort is
+/// trivially stable.
+pub fn sort_bools(array: &mut [bool]) {
+    let falses = array.iter().filter(|&&value| !value).count();
+
+    let (head, tail) = array.split_at_mut(falses);
+    head.iter_mut().for_each(|value| *value = false);
+    tail.iter_mut().for_each(|value| *value = true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let mut array: [bool; 0] = [];
+
+        sort_bools(&mut array);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let mut array = [true, false, true, false, false];
+
+        sort_bools(&mut array);
+
+        assert_eq!(array, [false, false, false, true, true]);
+    }
+
+    #[test]
+    fn it_sorts_uniform_arrays() {
+        let mut trues = [true; 4];
+        let mut falses = [false; 4];
+
+        sort_bools(&mut trues);
+        sort_bools(&mut falses);
+
+        assert_eq!(trues, [true; 4]);
+        assert_eq!(falses, [false; 4]);
+    }
+
+    #[test]
+    fn it_sorts_like_std_sort() {
+        let mut array: Vec<bool> = (0..100u32).map(|x| x * x % 7 < 3).collect();
+        let mut expected = array.clone();
+        expected.sort();
+
+        sort_bools(&mut array);
+
+        assert_eq!(array, expected);
+    }
+}
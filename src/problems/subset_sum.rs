@@ -0,0 +1,95 @@
+//! # Problem
+//! Given an array of non-negative integers **A** and a target **T**, decide
+//! whether some subset of **A** sums to exactly **T**. Each element can be
+//! used at most once. Empty subset sums to zero.
+//!
+//! ## Example
+//! Given `A = [3, 34, 4, 12, 5, 2]` and `T = 9`, output **`true`**, because
+//! `4 + 5 = 9`.
+
+/// Solves the problem in space O(T) and time O(N * T).
+///
+/// A table remembers for each sum `0..=T` whether it's reachable by a subset of
+/// the elements visited so far. Initially only zero is. Each element then
+/// extends every reachable sum `s` to `s + element`. The sums are updated from
+/// the largest down, so that a sum reached by the current element isn't
+/// extended by the same element again.
+///
+/// The time is pseudo-polynomial: it grows with the value of **T**, not with
+/// the length of its representation. The problem is NP-complete after all.
+pub fn subset_sum_exists(array: &[u32], target: u32) -> bool {
+    let target = target as usize;
+    let mut reachable = vec![false; target + 1];
+    reachable[0] = true;
+
+    for &element in array {
+        let element = element as usize;
+        if element > target {
+            continue;
+        }
+
+        for sum in (element..=target).rev() {
+            if reachable[sum - element] {
+                reachable[sum] = true;
+            }
+        }
+
+        if reachable[target] {
+            return true;
+        }
+    }
+
+    reachable[target]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        assert!(subset_sum_exists(&[], 0));
+        assert!(!subset_sum_exists(&[], 1));
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert!(subset_sum_exists(&[3, 34, 4, 12, 5, 2], 9));
+    }
+
+    #[test]
+    fn it_rejects_unreachable_target() {
+        assert!(!subset_sum_exists(&[3, 34, 4, 12, 5, 2], 30));
+    }
+
+    #[test]
+    fn zero_target_is_trivially_reachable() {
+        assert!(subset_sum_exists(&[3, 34, 4, 12, 5, 2], 0));
+    }
+
+    #[test]
+    fn it_uses_each_element_at_most_once() {
+        assert!(!subset_sum_exists(&[5], 10));
+        assert!(subset_sum_exists(&[5, 5], 10));
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let mut rng = SeededRng::new(seed);
+            let array: Vec<u32> = (0..rng.below(10)).map(|_| rng.below(20) as u32).collect();
+            let target = rng.below(60) as u32;
+
+            let brute_force = (0..1u32 << array.len()).any(|mask| {
+                let sum: u32 = (0..array.len())
+                    .filter(|&i| mask & (1 << i) != 0)
+                    .map(|i| array[i])
+                    .sum();
+                sum == target
+            });
+
+            assert_eq!(subset_sum_exists(&array, target), brute_force);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! # Problem
+//! Given an array **A** and a target **T**, find all distinct triples of
+//! values `(a, b, c)` taken from three different positions of **A** such that
+//! `a + b + c = T`.
+//!
+//! ## Example
+//! Given `A = [-1, 0, 1, 2, -1, -4]` and `T = 0`, output the value triples
+//! **`(-1, -1, 2)`** and **`(-1, 0, 1)`**.
+
+use super::argsort::argsort;
+
+/// Solves the problem in space O(N) and time O(N^2).
+///
+/// Each triple is output as indices into **A**, ordered so that the values
+/// they point to are ascending: `A[i] <= A[j] <= A[k]`. A triple of values is
+/// output only once even if **A** contains it at several positions, in which
+/// case the indices are the ones of its earliest occurrence in sorted order.
+/// Triples are output in ascending order of their values.
+///
+/// The indices are sorted by [`argsort`] so that the values can be scanned in
+/// order. Then each value in turn is fixed as the smallest of the triple, and
+/// the remaining two are found among the larger values by the two-tracker
+/// sweep of [`all_pairs_with_sum`]. Runs of equal values are skipped after
+/// the first of them has been used in a position, which avoids repeated
+/// triples. Sums are computed in `i128`, so they never overflow.
+///
+/// [`argsort`]: ../argsort/fn.argsort.html
+/// [`all_pairs_with_sum`]: ../two_sum/fn.all_pairs_with_sum.html
+pub fn three_sum(array: &[i64], target: i64) -> Vec<(usize, usize, usize)> {
+    let order = argsort(array);
+    let value = |position: usize| i128::from(array[order[position]]);
+    let target = i128::from(target);
+    let mut triples = Vec::new();
+
+    for first in 0..array.len().saturating_sub(2) {
+        if first > 0 && value(first) == value(first - 1) {
+            continue;
+        }
+
+        let mut low = first + 1;
+        let mut high = array.len() - 1;
+        while low < high {
+            let sum = value(first) + value(low) + value(high);
+
+            if sum < target {
+                low += 1;
+            } else if sum > target {
+                high -= 1;
+            } else {
+                triples.push((order[first], order[low], order[high]));
+
+                let (low_value, high_value) = (value(low), value(high));
+                while low < high && value(low) == low_value {
+                    low += 1;
+                }
+                while low < high && value(high) == high_value {
+                    high -= 1;
+                }
+            }
+        }
+    }
+
+    triples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(array: &[i64], triples: &[(usize, usize, usize)]) -> Vec<(i64, i64, i64)> {
+        triples
+            .iter()
+            .map(|&(i, j, k)| (array[i], array[j], array[k]))
+            .collect()
+    }
+
+    #[test]
+    fn it_handles_short_arrays() {
+        assert!(three_sum(&[], 0).is_empty());
+        assert!(three_sum(&[0, 0], 0).is_empty());
+        assert_eq!(three_sum(&[0, 0, 0], 0), vec![(0, 1, 2)]);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        let array = [-1, 0, 1, 2, -1, -4];
+
+        let triples = three_sum(&array, 0);
+
+        assert_eq!(values(&array, &triples), vec![(-1, -1, 2), (-1, 0, 1)]);
+        assert_eq!(triples, vec![(0, 4, 3), (0, 1, 2)]);
+    }
+
+    #[test]
+    fn it_outputs_nothing_without_solution() {
+        assert!(three_sum(&[1, 2, 4, 8, 16], 100).is_empty());
+        assert!(three_sum(&[1, 2, 4, 8, 16], 6).is_empty());
+    }
+
+    #[test]
+    fn it_skips_repeated_triples() {
+        let array = [2, 2, 2, 2, 0, 0, 4, 4, 1, 3];
+
+        let triples = three_sum(&array, 6);
+
+        assert_eq!(
+            values(&array, &triples),
+            vec![(0, 2, 4), (1, 2, 3), (2, 2, 2)]
+        );
+    }
+
+    #[test]
+    fn it_handles_extreme_values() {
+        let array = [i64::MAX, i64::MAX, i64::MIN, 1];
+
+        assert_eq!(
+            values(&array, &three_sum(&array, i64::MAX - 1)),
+            vec![(i64::MIN, i64::MAX, i64::MAX)]
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! # Problem
+//! Given an array **A** and a number **K**, find the **K** most frequent
+//! elements of **A**, from the most frequent one. If **A** has fewer than
+//! **K** distinct elements, output all of them. Elements which are equally
+//! frequent are ordered by their first occurrence in **A**.
+//!
+//! ## Example
+//! Given `A = [1, 1, 1, 2, 2, 3]` and `K = 2`, output `[1, 2]`.
+
+use super::frequencies::frequencies;
+use crate::algorithms_data_structures_programs::binary_heap::Heap;
+use std::hash::Hash;
+
+/// Solves the problem in space O(N) and time O(N + D log K), where **D** is
+/// the number of distinct elements.
+///
+/// Occurrences are counted with [`frequencies`]. Then each distinct element is
+/// pushed into a [`Heap`] together with its count and the index of its first
+/// occurrence. The heap holds at most **K** candidates and pops the weakest of
+/// them first: the least frequent one, and of equally frequent ones the one
+/// which occurs later. Whenever it grows over **K**, the weakest candidate is
+/// dropped. What's left is the answer, which is popped from the weakest and
+/// therefore reversed.
+///
+/// [`frequencies`]: ../frequencies/fn.frequencies.html
+/// [`Heap`]: ../../algorithms_data_structures_programs/binary_heap/struct.Heap.html
+pub fn top_k_frequent<T>(array: &[T], k: usize) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut counts = frequencies(array);
+    let mut heap = Heap::new(
+        |(a_count, a_index, _): &(usize, usize, T), (b_count, b_index, _)| {
+            b_count.cmp(a_count).then(a_index.cmp(b_index))
+        },
+    );
+
+    // Removing the count visits each distinct element only on its first
+    // occurrence.
+    for (index, element) in array.iter().enumerate() {
+        if let Some(count) = counts.remove(element) {
+            heap.push((count, index, element.clone()));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+    }
+
+    let mut top = Vec::with_capacity(heap.len());
+    while let Some((_, _, element)) = heap.pop() {
+        top.push(element);
+    }
+    top.reverse();
+
+    top
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert!(top_k_frequent(&array, 3).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(top_k_frequent(&[1, 1, 1, 2, 2, 3], 2), vec![1, 2]);
+    }
+
+    #[test]
+    fn it_clamps_k_to_distinct_count() {
+        assert_eq!(top_k_frequent(&[3, 1, 1, 1, 2, 2], 10), vec![1, 2, 3]);
+        assert!(top_k_frequent(&[1, 2], 0).is_empty());
+    }
+
+    #[test]
+    fn ties_go_to_first_occurrence() {
+        assert_eq!(
+            top_k_frequent(&['c', 'a', 'b', 'a', 'c', 'b'], 2),
+            vec!['c', 'a']
+        );
+    }
+
+    #[test]
+    fn it_agrees_with_full_sort() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let mut rng = SeededRng::new(seed);
+            let array: Vec<u32> = (0..rng.below(100)).map(|_| rng.below(15) as u32).collect();
+            let k = rng.below(20);
+
+            // Distinct elements in order of first occurrence, stably sorted
+            // by descending count.
+            let counts = frequencies(&array);
+            let mut distinct: Vec<u32> = Vec::new();
+            for &element in &array {
+                if !distinct.contains(&element) {
+                    distinct.push(element);
+                }
+            }
+            distinct.sort_by_key(|element| std::cmp::Reverse(counts[element]));
+            distinct.truncate(k);
+
+            assert_eq!(top_k_frequent(&array, k), distinct);
+        }
+    }
+}
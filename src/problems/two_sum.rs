@@ -0,0 +1,138 @@
+//! # Problem
+//! Given a sorted array **A** and a target **T**, find all pairs of indices
+//! `i < j` such that `A[i] + A[j] = T`.
+//!
+//! ## Example
+//! Given `A = [1, 2, 2, 3, 4, 5]` and `T = 6`, output
+//! **`[(0, 5), (1, 4), (2, 4)]`**.
+
+/// Solves the problem in space O(1) and time O(N + K), where K is the number
+/// of output pairs.
+///
+/// Two trackers start at the ends of the array. If their sum is too small,
+/// the left one moves right, if it's too large, the right one moves left.
+/// When the sum matches, each tracker takes in the whole run of elements
+/// equal to its own, as every element of one run pairs with every element of
+/// the other. If both trackers point to the same value, the runs are one run
+/// and all pairs within it match. Pairs come out sorted, because the left
+/// tracker only moves right and each of its runs is paired in order.
+///
+/// Sums are computed in `i128`, so they never overflow.
+pub fn all_pairs_with_sum(array: &[i64], target: i64) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    if array.is_empty() {
+        return pairs;
+    }
+
+    let target = i128::from(target);
+    let mut low = 0;
+    let mut high = array.len() - 1;
+
+    while low < high {
+        let sum = i128::from(array[low]) + i128::from(array[high]);
+
+        if sum < target {
+            low += 1;
+        } else if sum > target {
+            high -= 1;
+        } else if array[low] == array[high] {
+            for i in low..high {
+                for j in i + 1..=high {
+                    pairs.push((i, j));
+                }
+            }
+            break;
+        } else {
+            // Both runs are within the trackers, as the values differ.
+            let low_end = low
+                + array[low..]
+                    .iter()
+                    .take_while(|&&x| x == array[low])
+                    .count();
+            let high_start = high + 1
+                - array[..=high]
+                    .iter()
+                    .rev()
+                    .take_while(|&&x| x == array[high])
+                    .count();
+
+            for i in low..low_end {
+                for j in high_start..=high {
+                    pairs.push((i, j));
+                }
+            }
+
+            low = low_end;
+            high = high_start - 1;
+        }
+    }
+
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(array: &[i64], target: i64) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for i in 0..array.len() {
+            for j in i + 1..array.len() {
+                if array[i] + array[j] == target {
+                    pairs.push((i, j));
+                }
+            }
+        }
+
+        pairs
+    }
+
+    #[test]
+    fn it_handles_empty_array() {
+        assert!(all_pairs_with_sum(&[], 0).is_empty());
+        assert!(all_pairs_with_sum(&[3], 6).is_empty());
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(
+            all_pairs_with_sum(&[1, 2, 2, 3, 4, 5], 6),
+            vec![(0, 5), (1, 4), (2, 4)]
+        );
+    }
+
+    #[test]
+    fn it_pairs_runs_of_duplicates() {
+        let array = [1, 1, 2, 3, 3, 3, 5];
+
+        assert_eq!(
+            all_pairs_with_sum(&array, 4),
+            vec![(0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5)]
+        );
+        assert_eq!(
+            all_pairs_with_sum(&array, 6),
+            vec![(0, 6), (1, 6), (3, 4), (3, 5), (4, 5)]
+        );
+    }
+
+    #[test]
+    fn it_handles_extreme_values() {
+        let array = [i64::MIN, -1, 0, i64::MAX];
+
+        assert_eq!(all_pairs_with_sum(&array, -1), vec![(0, 3), (1, 2)]);
+        assert_eq!(all_pairs_with_sum(&array, i64::MAX - 1), vec![(1, 3)]);
+        assert!(all_pairs_with_sum(&array, i64::MIN + 1).is_empty());
+    }
+
+    #[test]
+    fn it_agrees_with_brute_force() {
+        let array = [-4, -2, -2, 0, 1, 1, 1, 2, 3, 4, 4, 6];
+
+        for target in -10..=12 {
+            assert_eq!(
+                all_pairs_with_sum(&array, target),
+                brute_force(&array, target)
+            );
+        }
+    }
+}
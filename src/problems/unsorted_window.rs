@@ -0,0 +1,102 @@
+//! # Problem
+//! Given an array **A**, find the shortest contiguous window such that
+//! sorting just the window makes the whole of **A** sorted.
+//!
+//! ## Example
+//! Given `A = [1, 2, 6, 4, 8, 10, 9, 15]`, output **`(2, 6)`**: sorting
+//! `[6, 4, 8, 10, 9]` yields `[1, 2, 4, 6, 8, 9, 10, 15]`.
+
+/// Solves the problem in space O(1) and time O(N).
+///
+/// Outputs inclusive indices of the first and the last element of the window,
+/// or `None` if the array is already sorted.
+///
+/// Every element which is smaller than its left neighbour, or larger than its
+/// right neighbour, has to move. The window therefore spans at least from the
+/// first such element to the last one. Sorting it moves its minimum to its
+/// start and its maximum to its end, so the window extends to the left over
+/// all elements larger than the minimum, and to the right over all elements
+/// smaller than the maximum.
+pub fn unsorted_window<T>(array: &[T]) -> Option<(usize, usize)>
+where
+    T: PartialOrd,
+{
+    let mut start = (1..array.len()).find(|&index| array[index] < array[index - 1])? - 1;
+    let mut end = (1..array.len())
+        .rev()
+        .find(|&index| array[index] < array[index - 1])?;
+
+    let mut min = &array[start];
+    let mut max = &array[start];
+    for element in &array[start..=end] {
+        if element < min {
+            min = element;
+        }
+        if element > max {
+            max = element;
+        }
+    }
+
+    while start > 0 && array[start - 1] > *min {
+        start -= 1;
+    }
+    while end + 1 < array.len() && array[end + 1] < *max {
+        end += 1;
+    }
+
+    Some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn it_handles_empty_array() {
+        let array: [u8; 0] = [];
+
+        assert_eq!(unsorted_window(&array), None);
+    }
+
+    #[test]
+    fn it_solves_example() {
+        assert_eq!(unsorted_window(&[1, 2, 6, 4, 8, 10, 9, 15]), Some((2, 6)));
+    }
+
+    #[test]
+    fn sorted_array_has_no_window() {
+        assert_eq!(unsorted_window(&[1, 2, 2, 3]), None);
+        assert_eq!(unsorted_window(&[1]), None);
+    }
+
+    #[test]
+    fn window_extends_over_elements_out_of_place() {
+        // The first violation is 5 > 3, but 4 also has to move.
+        assert_eq!(unsorted_window(&[1, 4, 5, 3, 6, 7]), Some((1, 3)));
+        // The last violation is 3 > 2, but 2.5 is smaller than the maximum 3.
+        assert_eq!(unsorted_window(&[1.0, 3.0, 2.0, 2.5, 4.0]), Some((1, 3)));
+        assert_eq!(unsorted_window(&[3, 2, 1]), Some((0, 2)));
+    }
+
+    #[test]
+    fn window_spans_elements_out_of_place() {
+        for seed in 0..u64::from(fuzzy_iterations()) {
+            let array = nearly_sorted_vec(30, 3, seed);
+            let mut sorted = array.clone();
+            sorted.sort();
+
+            // The elements are distinct, so the window spans exactly from the
+            // first to the last element which is not in its sorted position.
+            let out_of_place: Vec<_> = (0..array.len())
+                .filter(|&index| array[index] != sorted[index])
+                .collect();
+            let expected = match (out_of_place.first(), out_of_place.last()) {
+                (Some(&start), Some(&end)) => Some((start, end)),
+                _ => None,
+            };
+
+            assert_eq!(unsorted_window(&array), expected);
+        }
+    }
+}
@@ -1,6 +1,33 @@
 /// How many times to repeat fuzzy tests which are based on randomness.
 pub const FUZZY_TEST_ITERATIONS: u32 = 100;
 
+/// Environment variable which overrides [`FUZZY_TEST_ITERATIONS`].
+///
+/// [`FUZZY_TEST_ITERATIONS`]: constant.FUZZY_TEST_ITERATIONS.html
+pub const FUZZY_ITERATIONS_VAR: &str = "ADSP_FUZZ_ITERS";
+
+/// Returns how many times to repeat fuzzy tests. It's read from the
+/// `ADSP_FUZZ_ITERS` environment variable, so that a CI job can run many more
+/// iterations than a local `cargo test`, and defaults to
+/// [`FUZZY_TEST_ITERATIONS`].
+///
+/// Panics if the variable is set but is not a number, rather than quietly
+/// running the default number of iterations.
+///
+/// [`FUZZY_TEST_ITERATIONS`]: constant.FUZZY_TEST_ITERATIONS.html
+pub fn fuzzy_iterations() -> u32 {
+    parse_iterations(std::env::var(FUZZY_ITERATIONS_VAR).ok().as_deref())
+}
+
+fn parse_iterations(value: Option<&str>) -> u32 {
+    match value {
+        None => FUZZY_TEST_ITERATIONS,
+        Some(value) => value.trim().parse().unwrap_or_else(|_| {
+            panic!("{} must be a number, got {:?}", FUZZY_ITERATIONS_VAR, value)
+        }),
+    }
+}
+
 /// Returns whether slice is sorted.
 pub fn is_sorted<T>(array: &[T]) -> bool
 where
@@ -14,3 +41,456 @@ where
 
     true
 }
+
+/// Returns whether slice of floats is sorted, tolerating each element being at
+/// most `epsilon` below its predecessor. Rounding errors of floating point
+/// arithmetic would otherwise make strict checks with [`is_sorted`] flaky.
+///
+/// [`is_sorted`]: fn.is_sorted.html
+pub fn is_sorted_within(array: &[f64], epsilon: f64) -> bool {
+    array.windows(2).all(|pair| pair[1] >= pair[0] - epsilon)
+}
+
+/// Returns whether slice is a min-heap: every element on index `i` is not
+/// larger than its children on indices `2i + 1` and `2i + 2`.
+pub fn is_min_heap<T>(array: &[T]) -> bool
+where
+    T: PartialOrd,
+{
+    (1..array.len()).all(|child| array[(child - 1) / 2] <= array[child])
+}
+
+/// Deterministic pseudo random number generator [SplitMix64]. Tests use it
+/// instead of a thread local generator so that failures are reproducible.
+///
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        SeededRng { state: seed }
+    }
+
+    /// Returns next pseudo random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns pseudo random number in range `0..bound`.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// Shuffles the slice with Fisher-Yates algorithm. The same seed always
+/// produces the same permutation.
+pub fn shuffle<T>(array: &mut [T], seed: u64) {
+    let mut rng = SeededRng::new(seed);
+
+    // Picks an element from the unshuffled head and moves it to the shuffled
+    // tail.
+    for index in (1..array.len()).rev() {
+        array.swap(index, rng.below(index + 1));
+    }
+}
+
+/// Returns numbers `1..=len` in ascending order, disordered by given number of
+/// swaps of random neighbours. Useful for showing off adaptive sorts.
+pub fn nearly_sorted_vec(len: usize, swaps: usize, seed: u64) -> Vec<u32> {
+    let mut numbers: Vec<u32> = (1..=len as u32).collect();
+
+    // There are no neighbours to swap.
+    if len < 2 {
+        return numbers;
+    }
+
+    let mut rng = SeededRng::new(seed);
+    for _ in 0..swaps {
+        let index = rng.below(len - 1);
+        numbers.swap(index, index + 1);
+    }
+
+    numbers
+}
+
+/// Picks `k` items from a stream of unknown length, each with the same
+/// probability, while holding only `k` items in memory. If the stream has
+/// fewer than `k` items, all of them are returned. The same seed always picks
+/// the same items.
+///
+/// This is Algorithm R: the first `k` items fill the sample, and the `i`-th
+/// item after them (counting from zero) replaces a random sample item with
+/// probability `k / (i + 1)`.
+pub fn reservoir_sample<T, I>(iter: I, k: usize, seed: u64) -> Vec<T>
+where
+    I: Iterator<Item = T>,
+{
+    let mut rng = SeededRng::new(seed);
+    // A huge `k` mustn't be allocated up front, the stream may be short.
+    let mut sample = Vec::with_capacity(k.min(iter.size_hint().0));
+
+    for (index, item) in iter.enumerate() {
+        if index < k {
+            sample.push(item);
+        } else {
+            let slot = rng.below(index + 1);
+            if slot < k {
+                sample[slot] = item;
+            }
+        }
+    }
+
+    sample
+}
+
+/// Sorts a copy of the slice with given sorter and asserts that the output is
+/// sorted and has exactly the same elements as the input. A sort which
+/// overwrites an element with a copy of another one still outputs a sorted
+/// slice, which [`is_sorted`] alone would not catch.
+///
+/// [`is_sorted`]: fn.is_sorted.html
+pub fn assert_sorted_permutation<T>(original: &[T], sorter: fn(&mut [T]))
+where
+    T: Ord + Clone + std::fmt::Debug,
+{
+    let mut sorted = original.to_vec();
+
+    sorter(&mut sorted);
+
+    assert!(is_sorted(&sorted), "output is not sorted: {:?}", sorted);
+    let (missing, extra) = multiset_diff(original, &sorted);
+    assert!(
+        missing.is_empty() && extra.is_empty(),
+        "output is not a permutation of input, missing {:?} and extra {:?}",
+        missing,
+        extra
+    );
+}
+
+/// Element ordered only by its key. The index it carries is ignored by
+/// comparisons, so a sort can't use it to break ties.
+#[derive(Debug, Clone)]
+pub struct ByKey<K>(pub K, pub usize);
+
+impl<K: PartialEq> PartialEq for ByKey<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: PartialOrd> PartialOrd for ByKey<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+/// Sorts `(key, original_index)` pairs by their keys only and returns whether
+/// the sort was stable, i.e. whether the original indices within each group
+/// of equal keys are still ascending. The sorted pairs are written back.
+///
+/// Panics if the pairs don't end up sorted by key.
+pub fn sort_and_check_stable_by_key<K>(
+    array: &mut [(K, usize)],
+    sorter: fn(&mut [ByKey<K>]),
+) -> bool
+where
+    K: Ord + Clone,
+{
+    let mut wrapped: Vec<_> = array
+        .iter()
+        .map(|(key, index)| ByKey(key.clone(), *index))
+        .collect();
+
+    sorter(&mut wrapped);
+
+    for (pair, ByKey(key, index)) in array.iter_mut().zip(wrapped) {
+        *pair = (key, index);
+    }
+
+    assert!(
+        array.windows(2).all(|w| w[0].0 <= w[1].0),
+        "keys are not sorted"
+    );
+
+    array
+        .windows(2)
+        .all(|w| w[0].0 != w[1].0 || w[0].1 < w[1].1)
+}
+
+/// Returns `(key, original_index)` pairs for [`sort_and_check_stable_by_key`].
+/// The keys `0..keys` repeat until there are `len` pairs and are then shuffled
+/// with given seed, so that each key occurs many times in random order.
+///
+/// [`sort_and_check_stable_by_key`]: fn.sort_and_check_stable_by_key.html
+pub fn keyed_pairs(len: usize, keys: u32, seed: u64) -> Vec<(u32, usize)> {
+    let mut keys: Vec<u32> = (0..len as u32).map(|x| x % keys).collect();
+    shuffle(&mut keys, seed);
+
+    keys.into_iter().zip(0..).collect()
+}
+
+/// Compares two arrays as multisets. Returns elements which are in `a` but
+/// missing from `b`, and elements which are extra in `b`, both sorted. If the
+/// arrays are permutations of each other, both are empty. When a sort breaks,
+/// this shows exactly which elements were lost or duplicated.
+pub fn multiset_diff<T>(a: &[T], b: &[T]) -> (Vec<T>, Vec<T>)
+where
+    T: Ord + Clone,
+{
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort();
+    b.sort();
+
+    let mut missing = Vec::new();
+    let mut extra = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => {
+                missing.push(a[i].clone());
+                i += 1;
+            }
+            std::cmp::Ordering::Greater => {
+                extra.push(b[j].clone());
+                j += 1;
+            }
+            std::cmp::Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    missing.extend_from_slice(&a[i..]);
+    extra.extend_from_slice(&b[j..]);
+
+    (missing, extra)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_accepts_floats_sorted_within_epsilon() {
+        assert!(is_sorted_within(&[0.1, 0.2, 0.3], 1e-9));
+        assert!(is_sorted_within(&[0.1, 0.3 - 1e-12, 0.3], 1e-9));
+        assert!(is_sorted_within(&[0.3, 0.3 - 1e-12], 1e-9));
+        assert!(is_sorted_within(&[], 0.0));
+    }
+
+    #[test]
+    fn it_rejects_floats_dipping_below_epsilon() {
+        assert!(!is_sorted_within(&[0.1, 0.3, 0.2], 1e-9));
+        assert!(!is_sorted_within(&[0.3, 0.3 - 1e-6], 1e-9));
+        assert!(!is_sorted_within(&[0.1, f64::NAN], 1e-9));
+    }
+
+    #[test]
+    fn keyed_pairs_are_numbered_in_order() {
+        let pairs = keyed_pairs(30, 4, 2);
+
+        assert_eq!(pairs.len(), 30);
+        assert!(pairs.iter().enumerate().all(|(i, &(_, index))| i == index));
+        for key in 0..4 {
+            let count = pairs.iter().filter(|&&(k, _)| k == key).count();
+            assert_eq!(count, if key < 2 { 8 } else { 7 });
+        }
+    }
+
+    #[test]
+    fn it_recognizes_min_heaps() {
+        assert!(is_min_heap(&[1, 3, 2, 7, 4, 2]));
+        assert!(is_min_heap(&[5, 5, 5]));
+        assert!(is_min_heap::<u8>(&[]));
+        assert!(is_min_heap(&[1]));
+    }
+
+    #[test]
+    fn it_rejects_invalid_min_heaps() {
+        // The last element is smaller than its parent 2.
+        assert!(!is_min_heap(&[1, 3, 2, 7, 4, 1]));
+        assert!(!is_min_heap(&[2, 1]));
+    }
+
+    #[test]
+    fn reservoir_sample_picks_distinct_items_in_range() {
+        let mut sample = reservoir_sample(0..1000, 50, 3);
+
+        assert_eq!(sample.len(), 50);
+        assert!(sample.iter().all(|&x| x < 1000));
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 50);
+    }
+
+    #[test]
+    fn reservoir_sample_is_deterministic() {
+        assert_eq!(
+            reservoir_sample(0..1000, 20, 9),
+            reservoir_sample(0..1000, 20, 9)
+        );
+        assert_ne!(
+            reservoir_sample(0..1000, 20, 9),
+            reservoir_sample(0..1000, 20, 10)
+        );
+    }
+
+    #[test]
+    fn reservoir_sample_handles_short_streams() {
+        assert_eq!(reservoir_sample(0..3, 5, 1), vec![0, 1, 2]);
+        assert!(reservoir_sample(0..3, 0, 1).is_empty());
+        assert_eq!(reservoir_sample(0..3, usize::MAX, 1), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn reservoir_sample_is_roughly_uniform() {
+        // Each of 10 items should be picked about 3000 times out of 10000.
+        let mut counts = [0u32; 10];
+        for seed in 0..10_000 {
+            for item in reservoir_sample(0..10, 3, seed) {
+                counts[item] += 1;
+            }
+        }
+
+        assert!(counts.iter().all(|&count| (2700..3300).contains(&count)));
+    }
+
+    #[test]
+    fn it_accepts_sorted_permutation() {
+        assert_sorted_permutation(&[3, 1, 2, 1], |array| array.sort());
+        assert_sorted_permutation::<u8>(&[], |_| ());
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation")]
+    fn it_rejects_sorted_output_with_duplicated_element() {
+        assert_sorted_permutation(&[3, 1, 2], |array| {
+            array.sort();
+            array[1] = array[0];
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not sorted")]
+    fn it_rejects_unsorted_output() {
+        assert_sorted_permutation(&[3, 1, 2], |array| array.reverse());
+    }
+
+    #[test]
+    fn fuzzy_iterations_default_to_constant() {
+        if std::env::var_os(FUZZY_ITERATIONS_VAR).is_none() {
+            assert_eq!(fuzzy_iterations(), FUZZY_TEST_ITERATIONS);
+        }
+
+        assert_eq!(parse_iterations(None), FUZZY_TEST_ITERATIONS);
+    }
+
+    #[test]
+    fn fuzzy_iterations_are_overridden() {
+        assert_eq!(parse_iterations(Some("10000")), 10_000);
+        assert_eq!(parse_iterations(Some(" 5\n")), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fuzzy_iterations_reject_garbage() {
+        parse_iterations(Some("many"));
+    }
+
+    #[test]
+    fn shuffle_is_deterministic() {
+        let mut a: Vec<u32> = (0..50).collect();
+        let mut b: Vec<u32> = (0..50).collect();
+
+        shuffle(&mut a, 42);
+        shuffle(&mut b, 42);
+
+        assert_eq!(a, b);
+        assert_ne!(a, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn shuffle_outputs_permutation() {
+        let mut array: Vec<u32> = (0..50).collect();
+
+        shuffle(&mut array, 7);
+        array.sort();
+
+        assert_eq!(array, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn shuffle_handles_short_arrays() {
+        let mut empty: Vec<u32> = Vec::new();
+        let mut single = vec![1];
+
+        shuffle(&mut empty, 1);
+        shuffle(&mut single, 1);
+
+        assert_eq!(single, vec![1]);
+    }
+
+    #[test]
+    fn nearly_sorted_vec_without_swaps_is_sorted() {
+        assert_eq!(nearly_sorted_vec(10, 0, 1), (1..=10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn nearly_sorted_vec_is_permutation() {
+        for seed in 0..10 {
+            let mut numbers = nearly_sorted_vec(20, 5, seed);
+
+            assert!(!is_sorted(&numbers));
+
+            numbers.sort();
+            assert_eq!(numbers, (1..=20).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn nearly_sorted_vec_handles_short_vecs() {
+        assert!(nearly_sorted_vec(0, 5, 1).is_empty());
+        assert_eq!(nearly_sorted_vec(1, 5, 1), vec![1]);
+    }
+
+    #[test]
+    fn stability_check_detects_reordered_ties() {
+        let mut pairs = vec![(1, 0), (0, 1), (1, 2)];
+
+        let stable = sort_and_check_stable_by_key(&mut pairs, |array| array.swap(0, 1));
+        assert!(stable);
+        assert_eq!(pairs, vec![(0, 1), (1, 0), (1, 2)]);
+
+        let stable = sort_and_check_stable_by_key(&mut pairs, |array| array.swap(1, 2));
+        assert!(!stable);
+    }
+
+    #[test]
+    fn multiset_diff_of_permutations_is_empty() {
+        assert_eq!(
+            multiset_diff(&[3, 1, 2, 1], &[1, 1, 2, 3]),
+            (vec![], vec![])
+        );
+    }
+
+    #[test]
+    fn multiset_diff_reports_dropped_element() {
+        assert_eq!(multiset_diff(&[3, 1, 2, 1], &[1, 2, 3]), (vec![1], vec![]));
+    }
+
+    #[test]
+    fn multiset_diff_reports_duplicated_element() {
+        // A broken sort overwrote 4 with a copy of 2.
+        assert_eq!(multiset_diff(&[4, 2, 3], &[2, 2, 3]), (vec![4], vec![2]));
+    }
+}